@@ -0,0 +1,12 @@
+-- 마이그레이션 도입 이전에 init() 으로 만들어진 테이블이 있을 수 있으므로 IF NOT EXISTS 유지
+CREATE TABLE IF NOT EXISTS api_keys (
+    id UUID PRIMARY KEY,
+    device_id VARCHAR(255) NOT NULL,
+    api_key VARCHAR(255) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_keys_device_id ON api_keys(device_id);
+CREATE INDEX IF NOT EXISTS idx_api_keys_api_key ON api_keys(api_key);
@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS api_key_usage (
+    api_key_id UUID NOT NULL,
+    chain VARCHAR(64) NOT NULL,
+    network VARCHAR(64) NOT NULL,
+    method VARCHAR(128) NOT NULL,
+    bucket_start TIMESTAMPTZ NOT NULL,
+    request_count BIGINT NOT NULL DEFAULT 0,
+    error_count BIGINT NOT NULL DEFAULT 0,
+    response_bytes BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (api_key_id, chain, network, method, bucket_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_key_usage_bucket_start ON api_key_usage(bucket_start);
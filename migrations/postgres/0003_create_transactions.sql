@@ -0,0 +1,19 @@
+CREATE TABLE transactions (
+    id UUID PRIMARY KEY,
+    api_key_id UUID,
+    chain VARCHAR(64) NOT NULL,
+    network VARCHAR(64) NOT NULL,
+    hash VARCHAR(128),
+    sender VARCHAR(128),
+    upstream VARCHAR(64) NOT NULL,
+    accepted BOOLEAN NOT NULL,
+    error TEXT,
+    status VARCHAR(16) NOT NULL,
+    submitted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    checked_at TIMESTAMPTZ,
+    confirmed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_transactions_api_key_id ON transactions(api_key_id, submitted_at DESC);
+CREATE INDEX idx_transactions_hash ON transactions(hash);
+CREATE INDEX idx_transactions_pending ON transactions(submitted_at) WHERE status = 'pending';
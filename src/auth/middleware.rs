@@ -1,22 +1,37 @@
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
 };
 
-use crate::state::AppState;
+use sha2::{Digest, Sha256};
+use tracing::Instrument;
+
+use crate::{auth::ApiKey, state::AppState, telemetry::metrics};
 
 const API_KEY_HEADER: &str = "X-API-Key";
+const BEARER_PREFIX: &str = "Bearer ";
 
 pub async fn auth_middleware(
     State(state): State<AppState>,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, AuthError> {
-    let api_key = request
-        .headers()
+    let result = authenticate(&state, request.headers()).await;
+    metrics::record_auth(match &result {
+        Ok(_) => "success",
+        Err(err) => err.outcome(),
+    });
+
+    request.extensions_mut().insert(result?);
+
+    Ok(next.run(request).await)
+}
+
+async fn authenticate(state: &AppState, headers: &HeaderMap) -> Result<ApiKey, AuthError> {
+    let api_key = headers
         .get(API_KEY_HEADER)
         .and_then(|value| value.to_str().ok())
         .ok_or(AuthError::MissingApiKey)?;
@@ -24,6 +39,7 @@ pub async fn auth_middleware(
     let key_record = state
         .api_key_repo
         .find_by_api_key(api_key)
+        .instrument(tracing::info_span!("auth.lookup"))
         .await
         .map_err(|_| AuthError::InternalError)?
         .ok_or(AuthError::InvalidApiKey)?;
@@ -32,6 +48,33 @@ pub async fn auth_middleware(
         return Err(AuthError::ExpiredApiKey);
     }
 
+    Ok(key_record)
+}
+
+pub async fn admin_middleware(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AuthError> {
+    let settings = state.settings();
+    let expected = settings
+        .admin
+        .token
+        .as_deref()
+        .ok_or(AuthError::InvalidAdminToken)?;
+
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix(BEARER_PREFIX))
+        .ok_or(AuthError::InvalidAdminToken)?;
+
+    // 길이/내용에 따른 타이밍 차이를 줄이기 위해 해시끼리 비교
+    if Sha256::digest(token.as_bytes()) != Sha256::digest(expected.as_bytes()) {
+        return Err(AuthError::InvalidAdminToken);
+    }
+
     Ok(next.run(request).await)
 }
 
@@ -40,9 +83,22 @@ pub enum AuthError {
     MissingApiKey,
     InvalidApiKey,
     ExpiredApiKey,
+    InvalidAdminToken,
     InternalError,
 }
 
+impl AuthError {
+    fn outcome(&self) -> &'static str {
+        match self {
+            AuthError::MissingApiKey => "missing",
+            AuthError::InvalidApiKey => "invalid",
+            AuthError::ExpiredApiKey => "expired",
+            AuthError::InvalidAdminToken => "invalid_admin_token",
+            AuthError::InternalError => "error",
+        }
+    }
+}
+
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
         let (status, code, message) = match self {
@@ -53,6 +109,9 @@ impl IntoResponse for AuthError {
             ),
             AuthError::InvalidApiKey => (StatusCode::UNAUTHORIZED, -32003, "Invalid API Key"),
             AuthError::ExpiredApiKey => (StatusCode::UNAUTHORIZED, -32003, "API Key has expired"),
+            AuthError::InvalidAdminToken => {
+                (StatusCode::UNAUTHORIZED, -32003, "Invalid admin token")
+            }
             AuthError::InternalError => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 -32603,
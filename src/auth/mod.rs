@@ -2,6 +2,8 @@ pub mod middleware;
 pub mod model;
 pub mod repository;
 
-pub use middleware::auth_middleware;
+pub use middleware::{admin_middleware, auth_middleware};
 pub use model::ApiKey;
-pub use repository::ApiKeyRepository;
+pub use repository::{
+    ApiKeyRepository, MemoryApiKeyRepository, PostgresApiKeyRepository, SqliteApiKeyRepository,
+};
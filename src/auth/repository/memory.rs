@@ -0,0 +1,98 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use super::ApiKeyRepository;
+use crate::auth::model::ApiKey;
+
+// 프로세스 메모리에만 보관하므로 재시작하면 발급된 키가 모두 사라짐 (로컬 개발용)
+#[derive(Clone, Default)]
+pub struct MemoryApiKeyRepository {
+    keys: Arc<RwLock<HashMap<Uuid, ApiKey>>>,
+}
+
+impl MemoryApiKeyRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for MemoryApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), sqlx::Error> {
+        let mut keys = self.keys.write().unwrap_or_else(|e| e.into_inner());
+        keys.insert(api_key.id, api_key.clone());
+        Ok(())
+    }
+
+    async fn find_by_api_key(&self, api_key: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+        let keys = self.keys.read().unwrap_or_else(|e| e.into_inner());
+        Ok(keys.values().find(|key| key.api_key == api_key).cloned())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, sqlx::Error> {
+        let keys = self.keys.read().unwrap_or_else(|e| e.into_inner());
+        Ok(keys.get(&id).cloned())
+    }
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+        let keys = self.keys.read().unwrap_or_else(|e| e.into_inner());
+        Ok(keys
+            .values()
+            .filter(|key| key.device_id == device_id && key.is_active)
+            .max_by_key(|key| key.created_at)
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        device_id: Option<&str>,
+        include_inactive: bool,
+        limit: i64,
+    ) -> Result<Vec<ApiKey>, sqlx::Error> {
+        let keys = self.keys.read().unwrap_or_else(|e| e.into_inner());
+        let mut result: Vec<ApiKey> = keys
+            .values()
+            .filter(|key| device_id.is_none_or(|device_id| key.device_id == device_id))
+            .filter(|key| include_inactive || key.is_active)
+            .cloned()
+            .collect();
+        result.sort_by_key(|key| std::cmp::Reverse(key.created_at));
+        result.truncate(limit.max(0) as usize);
+        Ok(result)
+    }
+
+    async fn deactivate_by_device_id(&self, device_id: &str) -> Result<(), sqlx::Error> {
+        let mut keys = self.keys.write().unwrap_or_else(|e| e.into_inner());
+        for key in keys.values_mut().filter(|key| key.device_id == device_id) {
+            key.is_active = false;
+        }
+        Ok(())
+    }
+
+    async fn deactivate(&self, id: Uuid) -> Result<(), sqlx::Error> {
+        let mut keys = self.keys.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(key) = keys.get_mut(&id) {
+            key.is_active = false;
+        }
+        Ok(())
+    }
+
+    async fn cleanup_expired(&self) -> Result<u64, sqlx::Error> {
+        let now = Utc::now();
+        let mut keys = self.keys.write().unwrap_or_else(|e| e.into_inner());
+        let mut count = 0;
+        for key in keys.values_mut() {
+            if key.is_active && key.expires_at.is_some_and(|expires_at| expires_at < now) {
+                key.is_active = false;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
@@ -0,0 +1,36 @@
+mod memory;
+mod postgres;
+mod sqlite;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::model::ApiKey;
+
+pub use memory::MemoryApiKeyRepository;
+pub use postgres::PostgresApiKeyRepository;
+pub use sqlite::SqliteApiKeyRepository;
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), sqlx::Error>;
+
+    async fn find_by_api_key(&self, api_key: &str) -> Result<Option<ApiKey>, sqlx::Error>;
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, sqlx::Error>;
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Option<ApiKey>, sqlx::Error>;
+
+    async fn list(
+        &self,
+        device_id: Option<&str>,
+        include_inactive: bool,
+        limit: i64,
+    ) -> Result<Vec<ApiKey>, sqlx::Error>;
+
+    async fn deactivate_by_device_id(&self, device_id: &str) -> Result<(), sqlx::Error>;
+
+    async fn deactivate(&self, id: Uuid) -> Result<(), sqlx::Error>;
+
+    async fn cleanup_expired(&self) -> Result<u64, sqlx::Error>;
+}
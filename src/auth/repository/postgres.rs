@@ -1,46 +1,24 @@
+use async_trait::async_trait;
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use super::model::ApiKey;
+use super::ApiKeyRepository;
+use crate::auth::model::ApiKey;
 
 #[derive(Clone)]
-pub struct ApiKeyRepository {
+pub struct PostgresApiKeyRepository {
     pool: PgPool,
 }
 
-impl ApiKeyRepository {
+impl PostgresApiKeyRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+}
 
-    pub async fn init(&self) -> Result<(), sqlx::Error> {
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS api_keys (
-                id UUID PRIMARY KEY,
-                device_id VARCHAR(255) NOT NULL,
-                api_key VARCHAR(255) NOT NULL UNIQUE,
-                created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
-                expires_at TIMESTAMPTZ,
-                is_active BOOLEAN NOT NULL DEFAULT TRUE
-            )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        sqlx::query("CREATE INDEX IF NOT EXISTS idx_api_keys_device_id ON api_keys(device_id)")
-            .execute(&self.pool)
-            .await?;
-
-        sqlx::query("CREATE INDEX IF NOT EXISTS idx_api_keys_api_key ON api_keys(api_key)")
-            .execute(&self.pool)
-            .await?;
-
-        Ok(())
-    }
-
-    pub async fn create(&self, api_key: &ApiKey) -> Result<(), sqlx::Error> {
+#[async_trait]
+impl ApiKeyRepository for PostgresApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), sqlx::Error> {
         sqlx::query(
             r#"
             INSERT INTO api_keys (id, device_id, api_key, created_at, expires_at, is_active)
@@ -59,7 +37,7 @@ impl ApiKeyRepository {
         Ok(())
     }
 
-    pub async fn find_by_api_key(&self, api_key: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+    async fn find_by_api_key(&self, api_key: &str) -> Result<Option<ApiKey>, sqlx::Error> {
         let result = sqlx::query_as::<_, ApiKey>(
             r#"
             SELECT id, device_id, api_key, created_at, expires_at, is_active
@@ -74,7 +52,22 @@ impl ApiKeyRepository {
         Ok(result)
     }
 
-    pub async fn find_by_device_id(&self, device_id: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, sqlx::Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, device_id, api_key, created_at, expires_at, is_active
+            FROM api_keys
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Option<ApiKey>, sqlx::Error> {
         let result = sqlx::query_as::<_, ApiKey>(
             r#"
             SELECT id, device_id, api_key, created_at, expires_at, is_active
@@ -91,7 +84,31 @@ impl ApiKeyRepository {
         Ok(result)
     }
 
-    pub async fn deactivate_by_device_id(&self, device_id: &str) -> Result<(), sqlx::Error> {
+    async fn list(
+        &self,
+        device_id: Option<&str>,
+        include_inactive: bool,
+        limit: i64,
+    ) -> Result<Vec<ApiKey>, sqlx::Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, device_id, api_key, created_at, expires_at, is_active
+            FROM api_keys
+            WHERE ($1::VARCHAR IS NULL OR device_id = $1) AND ($2 OR is_active = TRUE)
+            ORDER BY created_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(device_id)
+        .bind(include_inactive)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn deactivate_by_device_id(&self, device_id: &str) -> Result<(), sqlx::Error> {
         sqlx::query(
             r#"
             UPDATE api_keys
@@ -106,7 +123,7 @@ impl ApiKeyRepository {
         Ok(())
     }
 
-    pub async fn deactivate(&self, id: Uuid) -> Result<(), sqlx::Error> {
+    async fn deactivate(&self, id: Uuid) -> Result<(), sqlx::Error> {
         sqlx::query(
             r#"
             UPDATE api_keys
@@ -121,7 +138,7 @@ impl ApiKeyRepository {
         Ok(())
     }
 
-    pub async fn cleanup_expired(&self) -> Result<u64, sqlx::Error> {
+    async fn cleanup_expired(&self) -> Result<u64, sqlx::Error> {
         let result = sqlx::query(
             r#"
             UPDATE api_keys
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use super::ApiKeyRepository;
+use crate::auth::model::ApiKey;
+
+#[derive(Clone)]
+pub struct SqliteApiKeyRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteApiKeyRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for SqliteApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO api_keys (id, device_id, api_key, created_at, expires_at, is_active)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(api_key.id)
+        .bind(&api_key.device_id)
+        .bind(&api_key.api_key)
+        .bind(api_key.created_at)
+        .bind(api_key.expires_at)
+        .bind(api_key.is_active)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_api_key(&self, api_key: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, device_id, api_key, created_at, expires_at, is_active
+            FROM api_keys
+            WHERE api_key = ?
+            "#,
+        )
+        .bind(api_key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ApiKey>, sqlx::Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, device_id, api_key, created_at, expires_at, is_active
+            FROM api_keys
+            WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn find_by_device_id(&self, device_id: &str) -> Result<Option<ApiKey>, sqlx::Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, device_id, api_key, created_at, expires_at, is_active
+            FROM api_keys
+            WHERE device_id = ? AND is_active = TRUE
+            ORDER BY julianday(created_at) DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(device_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn list(
+        &self,
+        device_id: Option<&str>,
+        include_inactive: bool,
+        limit: i64,
+    ) -> Result<Vec<ApiKey>, sqlx::Error> {
+        let result = sqlx::query_as::<_, ApiKey>(
+            r#"
+            SELECT id, device_id, api_key, created_at, expires_at, is_active
+            FROM api_keys
+            WHERE (?1 IS NULL OR device_id = ?1) AND (?2 OR is_active = TRUE)
+            ORDER BY julianday(created_at) DESC
+            LIMIT ?3
+            "#,
+        )
+        .bind(device_id)
+        .bind(include_inactive)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    async fn deactivate_by_device_id(&self, device_id: &str) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET is_active = FALSE
+            WHERE device_id = ?
+            "#,
+        )
+        .bind(device_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn deactivate(&self, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET is_active = FALSE
+            WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn cleanup_expired(&self) -> Result<u64, sqlx::Error> {
+        // 시각은 RFC 3339 문자열로 저장되므로 julianday 로 변환해 비교
+        let result = sqlx::query(
+            r#"
+            UPDATE api_keys
+            SET is_active = FALSE
+            WHERE expires_at IS NOT NULL AND julianday(expires_at) < julianday(?) AND is_active = TRUE
+            "#,
+        )
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
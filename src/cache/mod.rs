@@ -0,0 +1,48 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use serde_json::Value;
+
+// 메모리가 무한히 늘지 않도록 제한. 가득 차면 만료된 항목을 정리하고, 그래도 가득 차면 저장하지 않음
+const MAX_ENTRIES: usize = 10_000;
+
+// JSON-RPC 응답의 result 를 짧은 시간 동안 보관
+#[derive(Clone, Default)]
+pub struct ResponseCache {
+    entries: Arc<Mutex<HashMap<String, (Value, Instant)>>>,
+}
+
+impl ResponseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        let mut entries = self.entries.lock().ok()?;
+        match entries.get(key) {
+            Some((value, expires)) if *expires > Instant::now() => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: String, value: Value, ttl: Duration) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        if entries.len() >= MAX_ENTRIES {
+            entries.retain(|_, (_, expires)| *expires > now);
+        }
+        if entries.len() < MAX_ENTRIES {
+            entries.insert(key, (value, now + ttl));
+        }
+    }
+}
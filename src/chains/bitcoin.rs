@@ -0,0 +1,106 @@
+use sha2::{Digest, Sha256};
+
+use super::{DecodedTransaction, TransactionError};
+
+pub const SEND_RAW_TRANSACTION: &str = "sendrawtransaction";
+
+// Esplora 의 트랜잭션 전송 경로 (POST /tx, body 는 hex)
+pub const BROADCAST_PATH: &str = "tx";
+
+pub fn decode_transaction(raw: &str) -> Result<DecodedTransaction, TransactionError> {
+    let bytes = hex::decode(raw.trim()).map_err(|e| TransactionError::Encoding(e.to_string()))?;
+    let mut cursor = Cursor {
+        bytes: &bytes,
+        pos: 0,
+    };
+
+    let version = cursor.take(4)?;
+    let segwit = bytes.get(4..6) == Some(&[0x00, 0x01]);
+    if segwit {
+        cursor.take(2)?;
+    }
+
+    let body_start = cursor.pos;
+    let inputs = cursor.var_int()?;
+    if inputs == 0 {
+        return Err(TransactionError::Malformed("no inputs".to_string()));
+    }
+    for _ in 0..inputs {
+        cursor.take(36)?;
+        cursor.var_bytes()?;
+        cursor.take(4)?;
+    }
+
+    let outputs = cursor.var_int()?;
+    if outputs == 0 {
+        return Err(TransactionError::Malformed("no outputs".to_string()));
+    }
+    for _ in 0..outputs {
+        cursor.take(8)?;
+        cursor.var_bytes()?;
+    }
+    let body_end = cursor.pos;
+
+    if segwit {
+        for _ in 0..inputs {
+            for _ in 0..cursor.var_int()? {
+                cursor.var_bytes()?;
+            }
+        }
+    }
+
+    let lock_time = cursor.take(4)?;
+    if cursor.pos != bytes.len() {
+        return Err(TransactionError::Malformed("trailing bytes".to_string()));
+    }
+
+    // txid 는 witness 를 제외한 직렬화의 double SHA-256 (역순 표기)
+    let mut hasher = Sha256::new();
+    hasher.update(version);
+    hasher.update(&bytes[body_start..body_end]);
+    hasher.update(lock_time);
+    let mut txid = Sha256::digest(hasher.finalize()).to_vec();
+    txid.reverse();
+
+    Ok(DecodedTransaction {
+        hash: hex::encode(txid),
+        sender: None,
+        chain_id: None,
+    })
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], TransactionError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| {
+                TransactionError::Malformed("unexpected end of transaction".to_string())
+            })?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn var_int(&mut self) -> Result<usize, TransactionError> {
+        let value = match self.take(1)?[0] {
+            0xfd => u64::from(u16::from_le_bytes(self.take(2)?.try_into().unwrap())),
+            0xfe => u64::from(u32::from_le_bytes(self.take(4)?.try_into().unwrap())),
+            0xff => u64::from_le_bytes(self.take(8)?.try_into().unwrap()),
+            n => u64::from(n),
+        };
+        usize::try_from(value)
+            .map_err(|_| TransactionError::Malformed("length overflow".to_string()))
+    }
+
+    fn var_bytes(&mut self) -> Result<&'a [u8], TransactionError> {
+        let len = self.var_int()?;
+        self.take(len)
+    }
+}
@@ -0,0 +1,38 @@
+// Tendermint(CometBFT) RPC 의 URI 형식 엔드포인트 (`GET /status`, `GET /block?height=1` 등).
+// LCD(REST) 경로는 `cosmos/...`, `ibc/...` 처럼 모듈 이름으로 시작하므로 겹치지 않음
+pub const RPC_PATHS: &[&str] = &[
+    "abci_info",
+    "abci_query",
+    "block",
+    "block_by_hash",
+    "block_results",
+    "block_search",
+    "blockchain",
+    "broadcast_evidence",
+    "broadcast_tx_async",
+    "broadcast_tx_commit",
+    "broadcast_tx_sync",
+    "check_tx",
+    "commit",
+    "consensus_params",
+    "consensus_state",
+    "dump_consensus_state",
+    "genesis",
+    "genesis_chunked",
+    "header",
+    "header_by_hash",
+    "health",
+    "net_info",
+    "num_unconfirmed_txs",
+    "status",
+    "tx",
+    "tx_search",
+    "unconfirmed_txs",
+    "validators",
+];
+
+// 첫 경로 세그먼트가 Tendermint RPC 메서드 이름
+pub fn rpc_method(path: &str) -> Option<&str> {
+    let method = path.trim_matches('/').split('/').next()?;
+    RPC_PATHS.contains(&method).then_some(method)
+}
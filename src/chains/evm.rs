@@ -0,0 +1,267 @@
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use serde_json::Value;
+use sha3::{Digest, Keccak256};
+
+use super::{rlp, DecodedTransaction, TransactionError};
+
+pub const SEND_RAW_TRANSACTION: &str = "eth_sendRawTransaction";
+pub const GET_LOGS: &str = "eth_getLogs";
+pub const BLOCK_NUMBER: &str = "eth_blockNumber";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockRef {
+    Latest,
+    Earliest,
+    Number(u64),
+    Hash,
+}
+
+pub fn block_param_index(method: &str) -> Option<usize> {
+    match method {
+        "eth_getBlockByNumber"
+        | "eth_getBlockTransactionCountByNumber"
+        | "eth_getUncleCountByBlockNumber"
+        | "eth_getTransactionByBlockNumberAndIndex"
+        | "eth_getUncleByBlockNumberAndIndex"
+        | "eth_getBlockReceipts" => Some(0),
+        "eth_getBalance"
+        | "eth_getCode"
+        | "eth_getTransactionCount"
+        | "eth_call"
+        | "eth_estimateGas"
+        | "eth_createAccessList"
+        | "eth_feeHistory" => Some(1),
+        "eth_getStorageAt" | "eth_getProof" => Some(2),
+        _ => None,
+    }
+}
+
+pub fn block_ref(method: &str, params: &[Value]) -> Option<BlockRef> {
+    let index = block_param_index(method)?;
+    match params.get(index) {
+        None | Some(Value::Null) => Some(BlockRef::Latest),
+        Some(value) => parse_block_ref(value),
+    }
+}
+
+pub fn parse_block_ref(value: &Value) -> Option<BlockRef> {
+    match value {
+        Value::String(tag) => match tag.as_str() {
+            "latest" | "pending" | "safe" | "finalized" => Some(BlockRef::Latest),
+            "earliest" => Some(BlockRef::Earliest),
+            hex if hex.len() == 66 => Some(BlockRef::Hash),
+            hex => parse_quantity(hex).map(BlockRef::Number),
+        },
+        Value::Number(n) => n.as_u64().map(BlockRef::Number),
+        // EIP-1898: { "blockNumber": "0x.." } 또는 { "blockHash": "0x.." }
+        Value::Object(obj) => {
+            if let Some(number) = obj.get("blockNumber") {
+                parse_block_ref(number)
+            } else if obj.contains_key("blockHash") {
+                Some(BlockRef::Hash)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogBound {
+    Latest,
+    // "safe" / "finalized" 는 해당 블록 번호를 조회해 계산
+    Tagged(&'static str),
+    Number(u64),
+}
+
+// eth_getLogs 필터의 (fromBlock, toBlock). blockHash 필터는 None
+pub fn log_bounds(params: &[Value]) -> Option<(LogBound, LogBound)> {
+    let filter = params.first()?.as_object()?;
+    if filter.contains_key("blockHash") {
+        return None;
+    }
+
+    let bound = |key: &str| match filter.get(key) {
+        None | Some(Value::Null) => Some(LogBound::Latest),
+        Some(Value::String(tag)) => match tag.as_str() {
+            "latest" | "pending" => Some(LogBound::Latest),
+            "safe" => Some(LogBound::Tagged("safe")),
+            "finalized" => Some(LogBound::Tagged("finalized")),
+            "earliest" => Some(LogBound::Number(0)),
+            hex => parse_quantity(hex).map(LogBound::Number),
+        },
+        _ => None,
+    };
+    Some((bound("fromBlock")?, bound("toBlock")?))
+}
+
+pub fn parse_quantity(value: &str) -> Option<u64> {
+    let hex = value.strip_prefix("0x")?;
+    u64::from_str_radix(hex, 16).ok()
+}
+
+// 서명된 raw 트랜잭션의 해시 (keccak256)
+pub fn transaction_hash(raw: &str) -> Option<String> {
+    let bytes = hex::decode(raw.strip_prefix("0x").unwrap_or(raw)).ok()?;
+    if bytes.is_empty() {
+        return None;
+    }
+    Some(hash(&bytes))
+}
+
+// EIP-4844 네트워크 형식은 blob을 제외한 0x03 || rlp(tx_payload_body)가 해시 대상
+fn hash(bytes: &[u8]) -> String {
+    let payload = match bytes.split_first() {
+        Some((0x03, body)) => rlp::decode_list(body).ok().and_then(|items| {
+            items
+                .first()
+                .filter(|item| item.is_list)
+                .map(|item| item.raw)
+        }),
+        _ => None,
+    };
+    let digest = match payload {
+        Some(payload) => Keccak256::new()
+            .chain_update([0x03])
+            .chain_update(payload)
+            .finalize(),
+        None => Keccak256::digest(bytes),
+    };
+    format!("0x{}", hex::encode(digest))
+}
+
+pub fn decode_transaction(raw: &str) -> Result<DecodedTransaction, TransactionError> {
+    let bytes = hex::decode(raw.strip_prefix("0x").unwrap_or(raw))
+        .map_err(|e| TransactionError::Encoding(e.to_string()))?;
+    let hash = hash(&bytes);
+
+    let (chain_id, signing_payload, signature) = match bytes.first() {
+        Some(0xc0..=0xff) => decode_legacy(&bytes)?,
+        Some(tx_type @ 0x01..=0x04) => decode_typed(*tx_type, &bytes[1..])?,
+        Some(tx_type) => {
+            return Err(TransactionError::Malformed(format!(
+                "unsupported transaction type 0x{:02x}",
+                tx_type
+            )))
+        }
+        None => return Err(TransactionError::Malformed("empty transaction".to_string())),
+    };
+
+    let sender = recover_sender(&signing_payload, signature)?;
+    Ok(DecodedTransaction {
+        hash,
+        sender: Some(sender),
+        chain_id,
+    })
+}
+
+type SignatureParts<'a> = (u8, rlp::Item<'a>, rlp::Item<'a>);
+
+// [nonce, gasPrice, gas, to, value, data, v, r, s]
+fn decode_legacy(
+    bytes: &[u8],
+) -> Result<(Option<u64>, Vec<u8>, SignatureParts<'_>), TransactionError> {
+    let items = rlp::decode_list(bytes)?;
+    if items.len() != 9 {
+        return Err(TransactionError::Malformed(format!(
+            "legacy transaction has {} fields, expected 9",
+            items.len()
+        )));
+    }
+
+    let mut fields: Vec<&[u8]> = items[..6].iter().map(|item| item.raw).collect();
+    let v = items[6].as_u64()?;
+    let (chain_id, recovery_id, eip155) = match v {
+        27 | 28 => (None, (v - 27) as u8, Vec::new()),
+        35.. => {
+            let chain_id = (v - 35) / 2;
+            // EIP-155: 서명 대상에 chainId, 0, 0 포함
+            let mut suffix = rlp::encode_u64(chain_id);
+            suffix.extend_from_slice(&[0x80, 0x80]);
+            (Some(chain_id), ((v - 35) % 2) as u8, suffix)
+        }
+        _ => {
+            return Err(TransactionError::Signature(format!(
+                "invalid v value {}",
+                v
+            )))
+        }
+    };
+    if !eip155.is_empty() {
+        fields.push(&eip155);
+    }
+
+    let payload = rlp::encode_list(&fields);
+    Ok((chain_id, payload, (recovery_id, items[7], items[8])))
+}
+
+// 타입 트랜잭션: 첫 필드가 chainId, 마지막 세 필드가 yParity, r, s
+fn decode_typed(
+    tx_type: u8,
+    body: &[u8],
+) -> Result<(Option<u64>, Vec<u8>, SignatureParts<'_>), TransactionError> {
+    let mut items = rlp::decode_list(body)?;
+    // EIP-4844 네트워크 형식: [tx_payload_body, blobs, commitments, proofs]
+    if tx_type == 0x03 && items.first().is_some_and(|item| item.is_list) {
+        items = rlp::items(&items[0])?;
+    }
+
+    let expected = match tx_type {
+        0x01 => 11,
+        0x02 => 12,
+        0x03 => 14,
+        _ => 13,
+    };
+    if items.len() != expected {
+        return Err(TransactionError::Malformed(format!(
+            "type 0x{:02x} transaction has {} fields, expected {}",
+            tx_type,
+            items.len(),
+            expected
+        )));
+    }
+
+    let chain_id = items[0].as_u64()?;
+    let unsigned = expected - 3;
+    let recovery_id = match items[unsigned].as_u64()? {
+        parity @ (0 | 1) => parity as u8,
+        parity => {
+            return Err(TransactionError::Signature(format!(
+                "invalid y parity {}",
+                parity
+            )))
+        }
+    };
+
+    let fields: Vec<&[u8]> = items[..unsigned].iter().map(|item| item.raw).collect();
+    let mut payload = vec![tx_type];
+    payload.extend(rlp::encode_list(&fields));
+    Ok((
+        Some(chain_id),
+        payload,
+        (recovery_id, items[unsigned + 1], items[unsigned + 2]),
+    ))
+}
+
+fn recover_sender(
+    payload: &[u8],
+    (recovery_id, r, s): SignatureParts<'_>,
+) -> Result<String, TransactionError> {
+    let invalid = |e: k256::ecdsa::Error| TransactionError::Signature(e.to_string());
+
+    let signature = Signature::from_scalars(r.as_word()?, s.as_word()?).map_err(invalid)?;
+    if signature.normalize_s().is_some() {
+        return Err(TransactionError::Signature("high s value".to_string()));
+    }
+    let recovery_id = RecoveryId::from_byte(recovery_id)
+        .ok_or_else(|| TransactionError::Signature("invalid recovery id".to_string()))?;
+
+    let prehash = Keccak256::digest(payload);
+    let key =
+        VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).map_err(invalid)?;
+
+    let point = key.to_encoded_point(false);
+    let address = Keccak256::digest(&point.as_bytes()[1..]);
+    Ok(format!("0x{}", hex::encode(&address[12..])))
+}
@@ -0,0 +1,92 @@
+pub mod bitcoin;
+pub mod cosmos;
+pub mod evm;
+mod rlp;
+pub mod solana;
+pub mod stellar;
+pub mod sui;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{config::ChainKind, models::rpc::RpcRequest};
+
+#[derive(Debug, Clone)]
+pub struct DecodedTransaction {
+    pub hash: String,
+    pub sender: Option<String>,
+    pub chain_id: Option<u64>,
+}
+
+impl DecodedTransaction {
+    // 설정된 chain_id 와 트랜잭션의 chain_id 가 모두 있을 때만 비교
+    pub fn check_chain_id(&self, expected: Option<u64>) -> Result<(), TransactionError> {
+        match (expected, self.chain_id) {
+            (Some(expected), Some(actual)) if expected != actual => {
+                Err(TransactionError::ChainIdMismatch { expected, actual })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TransactionError {
+    #[error("missing transaction parameter")]
+    MissingParam,
+
+    #[error("invalid encoding: {0}")]
+    Encoding(String),
+
+    #[error("malformed transaction: {0}")]
+    Malformed(String),
+
+    #[error("invalid signature: {0}")]
+    Signature(String),
+
+    #[error("chain id mismatch: expected {expected}, got {actual}")]
+    ChainIdMismatch { expected: u64, actual: u64 },
+}
+
+// 서명된 트랜잭션을 전송하는 JSON-RPC 요청이면 디코딩, 아니면 None
+pub fn decode_jsonrpc(
+    kind: Option<ChainKind>,
+    request: &RpcRequest,
+) -> Result<Option<DecodedTransaction>, TransactionError> {
+    let params = &request.params;
+    let decoded = match (kind, request.method.as_str()) {
+        (Some(ChainKind::Evm), evm::SEND_RAW_TRANSACTION) => {
+            evm::decode_transaction(string_param(params, 0)?)?
+        }
+        (Some(ChainKind::Solana), solana::SEND_TRANSACTION) => {
+            let encoding = params
+                .get(1)
+                .and_then(|config| config.get("encoding"))
+                .and_then(Value::as_str);
+            solana::decode_transaction(string_param(params, 0)?, encoding)?
+        }
+        (Some(ChainKind::Sui), sui::EXECUTE_TRANSACTION_BLOCK) => {
+            let signatures = params
+                .get(1)
+                .and_then(Value::as_array)
+                .ok_or(TransactionError::MissingParam)?;
+            sui::decode_transaction(string_param(params, 0)?, signatures)?
+        }
+        (Some(ChainKind::Bitcoin), bitcoin::SEND_RAW_TRANSACTION) => {
+            let hex = request
+                .param(0, "hexstring")
+                .and_then(Value::as_str)
+                .ok_or(TransactionError::MissingParam)?;
+            bitcoin::decode_transaction(hex)?
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(decoded))
+}
+
+fn string_param(params: &[Value], index: usize) -> Result<&str, TransactionError> {
+    params
+        .get(index)
+        .and_then(Value::as_str)
+        .ok_or(TransactionError::MissingParam)
+}
@@ -0,0 +1,149 @@
+use super::TransactionError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Item<'a> {
+    pub raw: &'a [u8],
+    pub payload: &'a [u8],
+    pub is_list: bool,
+}
+
+impl Item<'_> {
+    pub fn as_u64(&self) -> Result<u64, TransactionError> {
+        if self.is_list || self.payload.len() > 8 || self.payload.first() == Some(&0) {
+            return Err(malformed("invalid integer"));
+        }
+        Ok(self
+            .payload
+            .iter()
+            .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte)))
+    }
+
+    pub fn as_word(&self) -> Result<[u8; 32], TransactionError> {
+        if self.is_list || self.payload.len() > 32 {
+            return Err(malformed("invalid 32-byte value"));
+        }
+        let mut word = [0u8; 32];
+        word[32 - self.payload.len()..].copy_from_slice(self.payload);
+        Ok(word)
+    }
+}
+
+// 입력 전체가 하나의 리스트여야 하며, 그 리스트의 항목들을 반환
+pub fn decode_list(input: &[u8]) -> Result<Vec<Item<'_>>, TransactionError> {
+    let (item, rest) = decode(input)?;
+    if !rest.is_empty() {
+        return Err(malformed("trailing bytes"));
+    }
+    items(&item)
+}
+
+pub fn items<'a>(list: &Item<'a>) -> Result<Vec<Item<'a>>, TransactionError> {
+    if !list.is_list {
+        return Err(malformed("expected a list"));
+    }
+
+    let mut items = Vec::new();
+    let mut rest = list.payload;
+    while !rest.is_empty() {
+        let (item, remaining) = decode(rest)?;
+        items.push(item);
+        rest = remaining;
+    }
+    Ok(items)
+}
+
+pub fn encode_list(items: &[&[u8]]) -> Vec<u8> {
+    let len: usize = items.iter().map(|item| item.len()).sum();
+    let mut out = header(0xc0, len);
+    for item in items {
+        out.extend_from_slice(item);
+    }
+    out
+}
+
+pub fn encode_u64(value: u64) -> Vec<u8> {
+    match value {
+        0 => vec![0x80],
+        1..=0x7f => vec![value as u8],
+        _ => {
+            let bytes = value.to_be_bytes();
+            let start = bytes.iter().position(|b| *b != 0).unwrap_or(7);
+            let mut out = header(0x80, 8 - start);
+            out.extend_from_slice(&bytes[start..]);
+            out
+        }
+    }
+}
+
+fn header(offset: u8, len: usize) -> Vec<u8> {
+    if len <= 55 {
+        return vec![offset + len as u8];
+    }
+    let bytes = (len as u64).to_be_bytes();
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(7);
+    let mut out = vec![offset + 55 + (8 - start) as u8];
+    out.extend_from_slice(&bytes[start..]);
+    out
+}
+
+fn decode(input: &[u8]) -> Result<(Item<'_>, &[u8]), TransactionError> {
+    let Some(&prefix) = input.first() else {
+        return Err(malformed("unexpected end of input"));
+    };
+
+    let (header_len, payload_len, is_list) = match prefix {
+        0x00..=0x7f => (0, 1, false),
+        0x80..=0xb7 => (1, usize::from(prefix - 0x80), false),
+        0xb8..=0xbf => long_length(input, prefix - 0xb7, false)?,
+        0xc0..=0xf7 => (1, usize::from(prefix - 0xc0), true),
+        0xf8..=0xff => long_length(input, prefix - 0xf7, true)?,
+    };
+
+    let end = header_len
+        .checked_add(payload_len)
+        .filter(|end| *end <= input.len())
+        .ok_or_else(|| malformed("length exceeds input"))?;
+
+    let payload = if prefix < 0x80 {
+        &input[..1]
+    } else {
+        &input[header_len..end]
+    };
+    if prefix == 0x81 && payload[0] < 0x80 {
+        return Err(malformed("non-canonical single byte"));
+    }
+
+    let item = Item {
+        raw: &input[..end],
+        payload,
+        is_list,
+    };
+    Ok((item, &input[end..]))
+}
+
+fn long_length(
+    input: &[u8],
+    len_of_len: u8,
+    is_list: bool,
+) -> Result<(usize, usize, bool), TransactionError> {
+    let len_of_len = usize::from(len_of_len);
+    let bytes = input
+        .get(1..1 + len_of_len)
+        .ok_or_else(|| malformed("unexpected end of input"))?;
+    if bytes[0] == 0 || len_of_len > 8 {
+        return Err(malformed("non-canonical length"));
+    }
+
+    let len = bytes
+        .iter()
+        .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+    let len = usize::try_from(len).map_err(|_| malformed("length exceeds input"))?;
+    if len <= 55 {
+        return Err(malformed("non-canonical length"));
+    }
+    Ok((1 + len_of_len, len, is_list))
+}
+
+fn malformed(reason: &str) -> TransactionError {
+    TransactionError::Malformed(format!("RLP: {}", reason))
+}
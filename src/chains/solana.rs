@@ -0,0 +1,180 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::Value;
+
+use super::{DecodedTransaction, TransactionError};
+
+pub const SEND_TRANSACTION: &str = "sendTransaction";
+pub const GET_SLOT: &str = "getSlot";
+pub const GET_PROGRAM_ACCOUNTS: &str = "getProgramAccounts";
+
+// commitment 에 따라 짧게 캐시해도 되는 조회 메서드. slot/blockhash 처럼 매 slot 바뀌는 값과
+// getBlock, getProgramAccounts 처럼 응답이 커질 수 있는 메서드는 제외
+const CACHEABLE_METHODS: &[&str] = &[
+    "getAccountInfo",
+    "getBalance",
+    "getBlockTime",
+    "getMinimumBalanceForRentExemption",
+    "getTokenAccountBalance",
+    "getTokenSupply",
+    "getTransaction",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+// 마지막 설정 객체의 commitment (지정하지 않으면 노드 기본값인 finalized)
+pub fn commitment(params: &[Value]) -> Commitment {
+    let commitment = params
+        .iter()
+        .rev()
+        .find_map(|param| param.get("commitment"))
+        .and_then(Value::as_str);
+
+    match commitment {
+        Some("processed" | "recent" | "single") => Commitment::Processed,
+        Some("confirmed" | "singleGossip") => Commitment::Confirmed,
+        _ => Commitment::Finalized,
+    }
+}
+
+pub fn is_cacheable(method: &str) -> bool {
+    CACHEABLE_METHODS.contains(&method)
+}
+
+pub fn skips_preflight(params: &[Value]) -> bool {
+    params
+        .get(1)
+        .and_then(|config| config.get("skipPreflight"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+pub fn has_filters(params: &[Value]) -> bool {
+    params
+        .get(1)
+        .and_then(|config| config.get("filters"))
+        .and_then(Value::as_array)
+        .is_some_and(|filters| !filters.is_empty())
+}
+
+// 패킷 크기 제한 (1280 - 40 - 8)
+const MAX_TRANSACTION_SIZE: usize = 1232;
+const SIGNATURE_LEN: usize = 64;
+const PUBKEY_LEN: usize = 32;
+
+pub fn decode_transaction(
+    encoded: &str,
+    encoding: Option<&str>,
+) -> Result<DecodedTransaction, TransactionError> {
+    let bytes = match encoding.unwrap_or("base58") {
+        "base58" => bs58::decode(encoded)
+            .into_vec()
+            .map_err(|e| TransactionError::Encoding(e.to_string()))?,
+        "base64" => STANDARD
+            .decode(encoded)
+            .map_err(|e| TransactionError::Encoding(e.to_string()))?,
+        other => {
+            return Err(TransactionError::Encoding(format!(
+                "unsupported encoding '{}'",
+                other
+            )))
+        }
+    };
+    if bytes.len() > MAX_TRANSACTION_SIZE {
+        return Err(TransactionError::Malformed(format!(
+            "transaction is {} bytes, limit is {}",
+            bytes.len(),
+            MAX_TRANSACTION_SIZE
+        )));
+    }
+
+    let mut cursor = Cursor::new(&bytes);
+    let num_signatures = cursor.short_vec()?;
+    if num_signatures == 0 {
+        return Err(TransactionError::Signature("no signatures".to_string()));
+    }
+    let first_signature = cursor.take(SIGNATURE_LEN)?;
+    cursor.take(SIGNATURE_LEN * (num_signatures - 1))?;
+
+    // 메시지: [버전 접두사] 헤더(3) 계정 목록 최근 블록해시 ...
+    let prefix = cursor.peek()?;
+    if prefix & 0x80 != 0 {
+        if prefix & 0x7f != 0 {
+            return Err(TransactionError::Malformed(format!(
+                "unsupported message version {}",
+                prefix & 0x7f
+            )));
+        }
+        cursor.take(1)?;
+    }
+
+    let header = cursor.take(3)?;
+    if usize::from(header[0]) != num_signatures {
+        return Err(TransactionError::Signature(format!(
+            "message requires {} signatures, got {}",
+            header[0], num_signatures
+        )));
+    }
+
+    let num_accounts = cursor.short_vec()?;
+    if num_accounts < num_signatures {
+        return Err(TransactionError::Malformed(
+            "fewer accounts than signers".to_string(),
+        ));
+    }
+    let fee_payer = cursor.take(PUBKEY_LEN)?;
+    cursor.take(PUBKEY_LEN * (num_accounts - 1))?;
+    cursor.take(32)?;
+
+    Ok(DecodedTransaction {
+        hash: bs58::encode(first_signature).into_string(),
+        sender: Some(bs58::encode(fee_payer).into_string()),
+        chain_id: None,
+    })
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    fn peek(&self) -> Result<u8, TransactionError> {
+        self.bytes.first().copied().ok_or_else(truncated)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], TransactionError> {
+        if len > self.bytes.len() {
+            return Err(truncated());
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    // compact-u16 길이 인코딩
+    fn short_vec(&mut self) -> Result<usize, TransactionError> {
+        let mut value = 0usize;
+        for shift in [0, 7, 14] {
+            let byte = self.take(1)?[0];
+            value |= usize::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(TransactionError::Malformed(
+            "invalid length prefix".to_string(),
+        ))
+    }
+}
+
+fn truncated() -> TransactionError {
+    TransactionError::Malformed("unexpected end of transaction".to_string())
+}
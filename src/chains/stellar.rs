@@ -0,0 +1,6 @@
+// Horizon 트랜잭션 제출 경로 (POST, form body tx=<base64 XDR>)
+pub const SUBMIT_PATHS: &[&str] = &["transactions", "transactions_async"];
+
+pub fn is_submit_path(path: &str) -> bool {
+    SUBMIT_PATHS.contains(&path.trim_matches('/'))
+}
@@ -0,0 +1,88 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use serde_json::Value;
+
+use super::{DecodedTransaction, TransactionError};
+
+pub const EXECUTE_TRANSACTION_BLOCK: &str = "sui_executeTransactionBlock";
+
+type Blake2b256 = Blake2b<U32>;
+
+const ED25519_FLAG: u8 = 0x00;
+const SECP256K1_FLAG: u8 = 0x01;
+const SECP256R1_FLAG: u8 = 0x02;
+const MULTISIG_FLAG: u8 = 0x03;
+const ZKLOGIN_FLAG: u8 = 0x05;
+const PASSKEY_FLAG: u8 = 0x06;
+
+pub fn decode_transaction(
+    tx_bytes: &str,
+    signatures: &[Value],
+) -> Result<DecodedTransaction, TransactionError> {
+    let bytes = decode_base64(tx_bytes)?;
+    // TransactionData 는 V1 만 존재
+    if bytes.first() != Some(&0) {
+        return Err(TransactionError::Malformed(
+            "unsupported TransactionData version".to_string(),
+        ));
+    }
+    if signatures.is_empty() {
+        return Err(TransactionError::Signature("no signatures".to_string()));
+    }
+
+    let mut signer = None;
+    for signature in signatures {
+        let signature = signature
+            .as_str()
+            .ok_or(TransactionError::MissingParam)
+            .and_then(decode_base64)?;
+        let address = check_signature(&signature)?;
+        signer = signer.or(address);
+    }
+
+    let mut hasher = Blake2b256::new();
+    hasher.update(b"TransactionData::");
+    hasher.update(&bytes);
+
+    Ok(DecodedTransaction {
+        hash: bs58::encode(hasher.finalize()).into_string(),
+        sender: signer,
+        chain_id: None,
+    })
+}
+
+// flag || signature || public key; 단일 서명이면 서명자 주소를 반환
+fn check_signature(signature: &[u8]) -> Result<Option<String>, TransactionError> {
+    let (flag, key_len) = match signature.first().copied() {
+        Some(ED25519_FLAG) => (ED25519_FLAG, 32),
+        Some(flag @ (SECP256K1_FLAG | SECP256R1_FLAG)) => (flag, 33),
+        Some(MULTISIG_FLAG | ZKLOGIN_FLAG | PASSKEY_FLAG) => return Ok(None),
+        Some(flag) => {
+            return Err(TransactionError::Signature(format!(
+                "unknown signature scheme 0x{:02x}",
+                flag
+            )))
+        }
+        None => return Err(TransactionError::Signature("empty signature".to_string())),
+    };
+
+    let expected = 1 + 64 + key_len;
+    if signature.len() != expected {
+        return Err(TransactionError::Signature(format!(
+            "signature is {} bytes, expected {}",
+            signature.len(),
+            expected
+        )));
+    }
+
+    let mut hasher = Blake2b256::new();
+    hasher.update([flag]);
+    hasher.update(&signature[65..]);
+    Ok(Some(format!("0x{}", hex::encode(hasher.finalize()))))
+}
+
+fn decode_base64(value: &str) -> Result<Vec<u8>, TransactionError> {
+    STANDARD
+        .decode(value)
+        .map_err(|e| TransactionError::Encoding(e.to_string()))
+}
@@ -0,0 +1,15 @@
+use super::{CliError, ConfigCommand};
+use crate::config::Settings;
+
+pub fn run(settings: &Settings, command: ConfigCommand) -> Result<(), CliError> {
+    match command {
+        ConfigCommand::Check => {
+            settings.validate().map_err(CliError::Invalid)?;
+            println!("Configuration OK ({} chains)", settings.chains.len());
+        }
+        ConfigCommand::Print => {
+            print!("{}", toml::to_string_pretty(&settings.redacted())?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use chrono::{Duration, Utc};
+
+use super::{CliError, KeySelector, KeysCommand};
+use crate::{
+    auth::{ApiKey, ApiKeyRepository},
+    config::{DatabaseBackend, Settings},
+    database::Database,
+};
+
+pub async fn run(settings: &Settings, command: KeysCommand) -> Result<(), CliError> {
+    if settings.database.backend == DatabaseBackend::Memory {
+        return Err(CliError::Unsupported(
+            "keys commands require the postgres or sqlite database backend".to_string(),
+        ));
+    }
+
+    let database = Database::connect(&settings.database).await?;
+    if settings.database.auto_migrate {
+        database.migrate().await?;
+    }
+
+    let repo = database.api_key_repo();
+    let result = execute(settings, repo.as_ref(), command).await;
+    database.close().await;
+    result
+}
+
+async fn execute(
+    settings: &Settings,
+    repo: &dyn ApiKeyRepository,
+    command: KeysCommand,
+) -> Result<(), CliError> {
+    match command {
+        KeysCommand::Issue {
+            device_id,
+            expires_in_days,
+        } => {
+            let expires_at = expires_in_days
+                .or(settings.auth.key_expiration_days)
+                .map(|days| Utc::now() + Duration::days(days));
+
+            repo.deactivate_by_device_id(&device_id).await?;
+            let api_key = ApiKey::new(device_id, expires_at);
+            repo.create(&api_key).await?;
+
+            // 전체 키는 발급 시에만 출력
+            println!("id:         {}", api_key.id);
+            println!("device_id:  {}", api_key.device_id);
+            println!("api_key:    {}", api_key.api_key);
+            println!("expires_at: {}", format_expiry(&api_key));
+        }
+        KeysCommand::Revoke(selector) => {
+            if let Some(device_id) = &selector.device {
+                repo.deactivate_by_device_id(device_id).await?;
+                println!("Revoked all keys of device '{}'", device_id);
+            } else {
+                let api_key = find(repo, &selector).await?;
+                repo.deactivate(api_key.id).await?;
+                println!(
+                    "Revoked key {} of device '{}'",
+                    api_key.id, api_key.device_id
+                );
+            }
+        }
+        KeysCommand::List { device, all, limit } => {
+            let keys = repo.list(device.as_deref(), all, limit).await?;
+            println!(
+                "{:<36}  {:<24}  {:<16}  {:<20}  {:<20}  STATUS",
+                "ID", "DEVICE", "KEY", "CREATED", "EXPIRES"
+            );
+            for api_key in &keys {
+                println!(
+                    "{:<36}  {:<24}  {:<16}  {:<20}  {:<20}  {}",
+                    api_key.id,
+                    api_key.device_id,
+                    mask(&api_key.api_key),
+                    api_key.created_at.format("%Y-%m-%d %H:%M:%S"),
+                    format_expiry(api_key),
+                    status(api_key)
+                );
+            }
+        }
+        KeysCommand::Show(selector) => {
+            let api_key = find(repo, &selector).await?;
+            println!("id:         {}", api_key.id);
+            println!("device_id:  {}", api_key.device_id);
+            println!("api_key:    {}", mask(&api_key.api_key));
+            println!("created_at: {}", api_key.created_at.to_rfc3339());
+            println!("expires_at: {}", format_expiry(&api_key));
+            println!("status:     {}", status(&api_key));
+        }
+    }
+
+    Ok(())
+}
+
+async fn find(repo: &dyn ApiKeyRepository, selector: &KeySelector) -> Result<ApiKey, CliError> {
+    let found = if let Some(id) = selector.id {
+        repo.find_by_id(id).await?
+    } else if let Some(key) = &selector.key {
+        repo.find_by_api_key(key).await?
+    } else if let Some(device_id) = &selector.device {
+        repo.find_by_device_id(device_id).await?
+    } else {
+        None
+    };
+
+    found.ok_or_else(|| CliError::NotFound("API key not found".to_string()))
+}
+
+fn mask(api_key: &str) -> String {
+    let prefix: String = api_key.chars().take(7).collect();
+    let suffix: String = api_key
+        .chars()
+        .skip(api_key.chars().count().saturating_sub(4))
+        .collect();
+    format!("{}…{}", prefix, suffix)
+}
+
+fn format_expiry(api_key: &ApiKey) -> String {
+    api_key
+        .expires_at
+        .map(|at| at.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "never".to_string())
+}
+
+fn status(api_key: &ApiKey) -> &'static str {
+    if !api_key.is_active {
+        "revoked"
+    } else if api_key.is_valid() {
+        "active"
+    } else {
+        "expired"
+    }
+}
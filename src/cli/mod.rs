@@ -0,0 +1,125 @@
+mod config;
+mod keys;
+
+use ::config::ConfigError;
+use clap::{Args, Parser, Subcommand};
+use sqlx::migrate::MigrateError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{config::Settings, database::Database};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Multi-chain RPC proxy")]
+pub struct Cli {
+    /// 설정 파일을 검증만 하고 종료 (`config check` 와 동일)
+    #[arg(long)]
+    pub check_config: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// API Key 발급/폐기/조회
+    #[command(subcommand)]
+    Keys(KeysCommand),
+    /// 설정 검증/출력
+    #[command(subcommand)]
+    Config(ConfigCommand),
+    /// 데이터베이스 스키마 마이그레이션을 적용하고 종료
+    Migrate,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum KeysCommand {
+    /// 디바이스에 새 API Key 발급 (기존 키는 비활성화)
+    Issue {
+        device_id: String,
+        /// 만료 기간 (일). 생략하면 auth.key_expiration_days 사용
+        #[arg(long)]
+        expires_in_days: Option<i64>,
+    },
+    /// API Key 비활성화
+    Revoke(KeySelector),
+    /// API Key 목록 (최신순)
+    List {
+        #[arg(long)]
+        device: Option<String>,
+        /// 비활성화된 키도 포함
+        #[arg(long)]
+        all: bool,
+        #[arg(long, default_value_t = 100)]
+        limit: i64,
+    },
+    /// API Key 상세 정보
+    Show(KeySelector),
+}
+
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+pub struct KeySelector {
+    #[arg(long)]
+    pub id: Option<Uuid>,
+    #[arg(long)]
+    pub key: Option<String>,
+    /// 디바이스 ID (revoke 는 해당 디바이스의 모든 키, show 는 최신 활성 키)
+    #[arg(long)]
+    pub device: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// 설정을 검증하고 종료 (포트 바인딩/DB 연결 없음)
+    Check,
+    /// 최종 설정을 TOML 로 출력 (비밀 값은 가림)
+    Print,
+}
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("Failed to load configuration: {0}")]
+    Load(#[from] ConfigError),
+
+    #[error("Configuration has {} problem(s):{}", .0.len(), .0.iter().map(|p| format!("\n  - {}", p)).collect::<String>())]
+    Invalid(Vec<String>),
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+
+    #[error("Migration failed: {0}")]
+    Migrate(#[from] MigrateError),
+
+    #[error("Failed to render configuration: {0}")]
+    Render(#[from] toml::ser::Error),
+
+    #[error("{0}")]
+    NotFound(String),
+
+    #[error("{0}")]
+    Unsupported(String),
+}
+
+pub async fn run(command: Command) -> Result<(), CliError> {
+    let settings = Settings::load()?;
+
+    match command {
+        Command::Keys(command) => keys::run(&settings, command).await,
+        Command::Config(command) => config::run(&settings, command),
+        Command::Migrate => migrate(&settings).await,
+    }
+}
+
+async fn migrate(settings: &Settings) -> Result<(), CliError> {
+    let database = Database::connect(&settings.database).await?;
+    let result = database.migrate().await;
+    database.close().await;
+    result?;
+
+    match database.latest_version() {
+        Some(version) => println!("Database schema is up to date (version {})", version),
+        None => println!("In-memory backend has no schema to migrate"),
+    }
+    Ok(())
+}
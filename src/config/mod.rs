@@ -1,3 +1,12 @@
+mod policy;
+mod redact;
+mod routing;
 mod settings;
+mod solana;
+mod validate;
 
+pub use policy::*;
+pub use redact::redact_url;
+pub use routing::*;
 pub use settings::*;
+pub use solana::*;
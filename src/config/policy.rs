@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+const EVM_DENIED_METHODS: &[&str] = &[
+    "admin_*",
+    "debug_*",
+    "engine_*",
+    "miner_*",
+    "personal_*",
+    "trace_*",
+];
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct MethodPolicy {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl MethodPolicy {
+    pub fn evm_default() -> Self {
+        Self {
+            allow: Vec::new(),
+            deny: EVM_DENIED_METHODS.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    pub fn permits(&self, method: &str) -> bool {
+        if self.deny.iter().any(|pattern| glob_match(pattern, method)) {
+            return false;
+        }
+
+        self.allow.is_empty() || self.allow.iter().any(|pattern| glob_match(pattern, method))
+    }
+}
+
+pub fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let value = value.as_bytes();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == b'*')
+}
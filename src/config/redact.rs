@@ -0,0 +1,107 @@
+use url::Url;
+
+use super::settings::{CredentialConfig, Settings};
+
+const REDACTED: &str = "***";
+
+impl Settings {
+    // `config print` 출력용 사본. 비밀 값과 업스트림 URL 에 박힌 키만 가리고 나머지 설정은 그대로 둠
+    pub fn redacted(&self) -> Settings {
+        let mut settings = self.clone();
+
+        settings.database.url = redact_url(&settings.database.url);
+        redact(&mut settings.auth.client_secret);
+        redact(&mut settings.admin.token);
+
+        for chain in settings.chains.values_mut() {
+            for network in chain.networks_mut() {
+                redact(&mut network.api_key);
+                redact_credential(network.credential.as_mut());
+                redact_urls(network.jsonrpc_url.iter_mut());
+                redact_urls(network.rest_url.iter_mut());
+                redact_urls(network.broadcast_urls.iter_mut());
+                for group in network.upstreams.values_mut() {
+                    redact_credential(group.credential.as_mut());
+                    redact_urls(group.urls.iter_mut());
+                }
+            }
+        }
+
+        settings
+    }
+}
+
+fn redact(value: &mut Option<String>) {
+    if value.is_some() {
+        *value = Some(REDACTED.to_string());
+    }
+}
+
+fn redact_urls<'a>(urls: impl Iterator<Item = &'a mut String>) {
+    for url in urls {
+        *url = redact_url(url);
+    }
+}
+
+fn redact_credential(credential: Option<&mut CredentialConfig>) {
+    if let Some(credential) = credential {
+        redact(&mut credential.key);
+    }
+}
+
+// URL 에 박힌 키를 가림: userinfo, 쿼리 값, 키처럼 보이는 경로 세그먼트 (Alchemy/Infura 의 /v2/<key> 등).
+// 파싱할 수 없으면 전체를 가림
+pub fn redact_url(value: &str) -> String {
+    let Ok(mut url) = Url::parse(value) else {
+        return REDACTED.to_string();
+    };
+    let has_userinfo = !url.username().is_empty() || url.password().is_some();
+    let has_key_segment = url.path().split('/').any(looks_like_key);
+    if !has_userinfo && url.query().is_none() && !has_key_segment {
+        return value.to_string();
+    }
+
+    if !url.username().is_empty() {
+        let _ = url.set_username(REDACTED);
+    }
+    if url.password().is_some() {
+        let _ = url.set_password(Some(REDACTED));
+    }
+
+    if url.query().is_some() {
+        let query = url
+            .query_pairs()
+            .map(|(name, _)| format!("{}={}", name, REDACTED))
+            .collect::<Vec<_>>()
+            .join("&");
+        url.set_query(Some(&query));
+    }
+
+    if has_key_segment {
+        let path = url
+            .path()
+            .split('/')
+            .map(|segment| {
+                if looks_like_key(segment) {
+                    REDACTED
+                } else {
+                    segment
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        url.set_path(&path);
+    }
+
+    // {api_key} 자리 표시자는 읽을 수 있게 그대로 둠
+    url.to_string().replace("%7Bapi_key%7D", "{api_key}")
+}
+
+fn looks_like_key(segment: &str) -> bool {
+    segment.len() >= 20
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && segment.chars().any(|c| c.is_ascii_digit())
+        && segment.chars().any(|c| c.is_ascii_alphabetic())
+}
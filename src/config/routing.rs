@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::settings::CredentialConfig;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpstreamGroupConfig {
+    pub urls: Vec<String>,
+    pub credential: Option<CredentialConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockSelector {
+    Latest,
+    Historical,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RouteRule {
+    pub methods: Vec<String>,
+    pub block: Option<BlockSelector>,
+    pub upstream: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockPinningMode {
+    Rewrite,
+    Route,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlockPinningConfig {
+    pub mode: BlockPinningMode,
+    #[serde(default = "default_head_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_max_lag")]
+    pub max_lag: u64,
+}
+
+fn default_head_poll_interval_secs() -> u64 {
+    2
+}
+
+fn default_max_lag() -> u64 {
+    5
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GetLogsConfig {
+    #[serde(default = "default_logs_max_range")]
+    pub max_range: u64,
+    #[serde(default = "default_logs_concurrency")]
+    pub concurrency: usize,
+    #[serde(default = "default_logs_max_total_range")]
+    pub max_total_range: u64,
+    #[serde(default)]
+    pub device_limits: HashMap<String, u64>,
+}
+
+fn default_logs_max_range() -> u64 {
+    2000
+}
+
+fn default_logs_concurrency() -> usize {
+    4
+}
+
+fn default_logs_max_total_range() -> u64 {
+    100_000
+}
+
+impl GetLogsConfig {
+    pub fn limit_for(&self, device_id: Option<&str>) -> u64 {
+        device_id
+            .and_then(|device_id| self.device_limits.get(device_id))
+            .copied()
+            .unwrap_or(self.max_total_range)
+    }
+}
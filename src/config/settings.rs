@@ -1,20 +1,70 @@
-use config::{Config, ConfigError, File};
-use serde::Deserialize;
-use std::collections::HashMap;
+use config::{Config, ConfigError, Environment, File};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
 
-#[derive(Debug, Deserialize, Clone)]
+use super::{
+    policy::MethodPolicy,
+    routing::{BlockPinningConfig, GetLogsConfig, RouteRule, UpstreamGroupConfig},
+    solana::SolanaConfig,
+};
+
+const ENV_PREFIX: &str = "ARPC";
+const ENV_SEPARATOR: &str = "__";
+const FILE_SUFFIX: &str = "_FILE";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    #[serde(default = "default_shutdown_timeout_secs")]
+    pub shutdown_timeout_secs: u64,
+}
+
+fn default_shutdown_timeout_secs() -> u64 {
+    30
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    #[default]
+    Postgres,
+    Sqlite,
+    Memory,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DatabaseConfig {
+    #[serde(default)]
+    pub backend: DatabaseBackend,
+    #[serde(default)]
     pub url: String,
+    #[serde(default = "default_max_connections")]
     pub max_connections: u32,
+    #[serde(default = "default_auto_migrate")]
+    pub auto_migrate: bool,
+}
+
+fn default_max_connections() -> u32 {
+    5
 }
 
-#[derive(Debug, Deserialize, Clone)]
+fn default_auto_migrate() -> bool {
+    true
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            backend: DatabaseBackend::default(),
+            url: String::new(),
+            max_connections: default_max_connections(),
+            auto_migrate: default_auto_migrate(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AuthConfig {
     pub enabled: bool,
     pub key_expiration_days: Option<i64>,
@@ -38,12 +88,202 @@ impl Default for AuthConfig {
     }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MetricsConfig {
+    #[serde(default = "default_metrics_enabled")]
+    pub enabled: bool,
+}
+
+fn default_metrics_enabled() -> bool {
+    true
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_metrics_enabled(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LoggingConfig {
+    #[serde(default)]
+    pub access_log: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UsageConfig {
+    #[serde(default = "default_usage_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_usage_bucket_secs")]
+    pub bucket_secs: i64,
+    #[serde(default = "default_usage_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+}
+
+fn default_usage_enabled() -> bool {
+    true
+}
+
+fn default_usage_bucket_secs() -> i64 {
+    3600
+}
+
+fn default_usage_flush_interval_secs() -> u64 {
+    60
+}
+
+impl Default for UsageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_usage_enabled(),
+            bucket_secs: default_usage_bucket_secs(),
+            flush_interval_secs: default_usage_flush_interval_secs(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TransactionsConfig {
+    #[serde(default = "default_transactions_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_transactions_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    #[serde(default = "default_transactions_drop_after_secs")]
+    pub drop_after_secs: i64,
+    #[serde(default = "default_transactions_recheck_dropped_secs")]
+    pub recheck_dropped_secs: i64,
+    #[serde(default = "default_transactions_batch_size")]
+    pub batch_size: i64,
+}
+
+fn default_transactions_enabled() -> bool {
+    true
+}
+
+fn default_transactions_poll_interval_secs() -> u64 {
+    30
+}
+
+fn default_transactions_drop_after_secs() -> i64 {
+    1800
+}
+
+fn default_transactions_recheck_dropped_secs() -> i64 {
+    86400
+}
+
+fn default_transactions_batch_size() -> i64 {
+    100
+}
+
+impl Default for TransactionsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_transactions_enabled(),
+            poll_interval_secs: default_transactions_poll_interval_secs(),
+            drop_after_secs: default_transactions_drop_after_secs(),
+            recheck_dropped_secs: default_transactions_recheck_dropped_secs(),
+            batch_size: default_transactions_batch_size(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReloadConfig {
+    #[serde(default)]
+    pub watch: bool,
+    #[serde(default = "default_reload_interval_secs")]
+    pub interval_secs: u64,
+}
+
+fn default_reload_interval_secs() -> u64 {
+    5
+}
+
+impl Default for ReloadConfig {
+    fn default() -> Self {
+        Self {
+            watch: false,
+            interval_secs: default_reload_interval_secs(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AdminConfig {
+    pub bind: Option<String>,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialPlacement {
+    #[default]
+    Query,
+    Path,
+    Header,
+    Bearer,
+    Basic,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CredentialConfig {
+    #[serde(default)]
+    pub placement: CredentialPlacement,
+    pub name: Option<String>,
+    pub username: Option<String>,
+    pub key: Option<String>,
+    pub key_env: Option<String>,
+    pub key_file: Option<String>,
+    // key_env / key_file 을 읽지 못한 이유. 검증 단계에서 에러로 보고
+    #[serde(skip)]
+    pub resolve_error: Option<String>,
+}
+
+impl CredentialConfig {
+    fn resolve(&mut self) {
+        self.resolve_error = None;
+        match (&self.key_env, &self.key_file) {
+            // 둘 다 설정된 경우는 검증에서 거부
+            (Some(_), Some(_)) => self.key = None,
+            (Some(var), None) => match std::env::var(var) {
+                Ok(value) => self.key = Some(value.trim().to_string()),
+                Err(_) => {
+                    self.key = None;
+                    self.resolve_error = Some(format!("environment variable '{}' is not set", var));
+                }
+            },
+            (None, Some(path)) => match std::fs::read_to_string(path) {
+                Ok(value) => self.key = Some(value.trim().to_string()),
+                Err(e) => {
+                    self.key = None;
+                    self.resolve_error = Some(format!("failed to read key file '{}': {}", path, e));
+                }
+            },
+            (None, None) => {}
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NetworkConfig {
     pub name: String,
     pub jsonrpc_url: Option<String>,
     pub rest_url: Option<String>,
     pub api_key: Option<String>,
+    pub credential: Option<CredentialConfig>,
+    #[serde(default)]
+    pub allowed_paths: Vec<String>,
+    pub method_policy: Option<MethodPolicy>,
+    #[serde(default)]
+    pub upstreams: HashMap<String, UpstreamGroupConfig>,
+    #[serde(default)]
+    pub routes: Vec<RouteRule>,
+    #[serde(default)]
+    pub broadcast_urls: Vec<String>,
+    pub chain_id: Option<u64>,
 }
 
 impl NetworkConfig {
@@ -54,14 +294,70 @@ impl NetworkConfig {
     pub fn has_rest(&self) -> bool {
         self.rest_url.is_some()
     }
+
+    fn resolve_credential(&mut self) {
+        if self.credential.is_none() {
+            // 기존 `api_key` 설정은 `?apikey=` 쿼리 파라미터로 취급
+            if let Some(key) = &self.api_key {
+                self.credential = Some(CredentialConfig {
+                    key: Some(key.clone()),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if let Some(credential) = &mut self.credential {
+            credential.resolve();
+        }
+
+        for upstream in self.upstreams.values_mut() {
+            if let Some(credential) = &mut upstream.credential {
+                credential.resolve();
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CorsConfig {
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+}
+
+impl CorsConfig {
+    pub fn allows(&self, origin: &str) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|allowed| allowed == "*" || allowed.eq_ignore_ascii_case(origin))
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainKind {
+    Evm,
+    Solana,
+    Sui,
+    Bitcoin,
+    Cosmos,
+    Stellar,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ChainConfig {
     pub name: String,
+    pub kind: Option<ChainKind>,
     pub mainnet: NetworkConfig,
     #[serde(default)]
     pub testnets: HashMap<String, NetworkConfig>,
+    pub cors: Option<CorsConfig>,
+    pub method_policy: Option<MethodPolicy>,
+    #[serde(default)]
+    pub broadcast: bool,
+    pub block_pinning: Option<BlockPinningConfig>,
+    pub get_logs: Option<GetLogsConfig>,
+    // kind = "solana" 체인에만 적용
+    pub solana: Option<SolanaConfig>,
 }
 
 impl ChainConfig {
@@ -71,27 +367,99 @@ impl ChainConfig {
             Some(net) => self.testnets.get(net),
         }
     }
+
+    // "mainnet" 또는 testnets 의 키
+    pub fn network(&self, id: &str) -> Option<&NetworkConfig> {
+        self.get_network((id != "mainnet").then_some(id))
+    }
+
+    // bitcoin 체인은 rest_url 만 있어도 Esplora 어댑터로 JSON-RPC 요청을 처리
+    pub fn serves_jsonrpc(&self, network: &NetworkConfig) -> bool {
+        network.has_jsonrpc() || (self.kind == Some(ChainKind::Bitcoin) && network.has_rest())
+    }
+
+    pub fn is_method_allowed(&self, network: &NetworkConfig, method: &str) -> bool {
+        // 네트워크 정책이 있으면 체인 정책을 대체
+        if let Some(policy) = network
+            .method_policy
+            .as_ref()
+            .or(self.method_policy.as_ref())
+        {
+            return policy.permits(method);
+        }
+
+        match self.kind {
+            Some(ChainKind::Evm) => MethodPolicy::evm_default().permits(method),
+            _ => true,
+        }
+    }
+
+    pub(super) fn networks_mut(&mut self) -> impl Iterator<Item = &mut NetworkConfig> {
+        std::iter::once(&mut self.mainnet).chain(self.testnets.values_mut())
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Settings {
     pub server: ServerConfig,
+    #[serde(default)]
     pub database: DatabaseConfig,
     #[serde(default)]
     pub auth: AuthConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub usage: UsageConfig,
+    #[serde(default)]
+    pub transactions: TransactionsConfig,
+    #[serde(default)]
+    pub admin: AdminConfig,
+    #[serde(default)]
+    pub reload: ReloadConfig,
     pub chains: HashMap<String, ChainConfig>,
 }
 
 impl Settings {
     pub fn load() -> Result<Self, ConfigError> {
-        let run_mode = std::env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
+        let dir = config_dir();
+
+        let mut builder = Config::builder()
+            .add_source(File::from(dir.join("default")))
+            .add_source(File::from(dir.join(run_mode())).required(false))
+            .add_source(
+                Environment::with_prefix(ENV_PREFIX)
+                    .prefix_separator(ENV_SEPARATOR)
+                    .separator(ENV_SEPARATOR)
+                    .try_parsing(true),
+            );
 
-        let config = Config::builder()
-            .add_source(File::with_name("config/default"))
-            .add_source(File::with_name(&format!("config/{}", run_mode)).required(false))
-            .build()?;
+        for (key, value) in file_overrides()? {
+            builder = builder.set_override(key, value)?;
+        }
+
+        let config = builder.build()?;
 
-        config.try_deserialize()
+        let mut settings: Settings = config.try_deserialize()?;
+        settings.resolve_credentials();
+        Ok(settings)
+    }
+
+    pub fn source_files() -> Vec<PathBuf> {
+        let dir = config_dir();
+        vec![
+            dir.join("default.toml"),
+            dir.join(format!("{}.toml", run_mode())),
+        ]
+    }
+
+    fn resolve_credentials(&mut self) {
+        for chain in self.chains.values_mut() {
+            for network in chain.networks_mut() {
+                network.resolve_credential();
+            }
+        }
     }
 
     pub fn get_chain(&self, chain_id: &str) -> Option<&ChainConfig> {
@@ -106,3 +474,42 @@ impl Settings {
         format!("{}:{}", self.server.host, self.server.port)
     }
 }
+
+fn run_mode() -> String {
+    std::env::var("RUN_MODE").unwrap_or_else(|_| "development".into())
+}
+
+fn config_dir() -> PathBuf {
+    std::env::var("ARPC_CONFIG_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("config"))
+}
+
+// ARPC__DATABASE__URL_FILE=/run/secrets/db_url 처럼 `_FILE` 로 끝나는 환경 변수는
+// 파일 내용을 읽어 해당 키(database.url)의 값으로 사용 (Docker/Kubernetes secret)
+fn file_overrides() -> Result<Vec<(String, String)>, ConfigError> {
+    let prefix = format!("{}{}", ENV_PREFIX, ENV_SEPARATOR);
+    let mut overrides = Vec::new();
+
+    for (name, path) in std::env::vars() {
+        let Some(key) = name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(FILE_SUFFIX))
+        else {
+            continue;
+        };
+
+        let value = std::fs::read_to_string(&path).map_err(|e| {
+            ConfigError::Message(format!("{}: failed to read '{}': {}", name, path, e))
+        })?;
+
+        let key = key
+            .split(ENV_SEPARATOR)
+            .map(|part| part.to_lowercase())
+            .collect::<Vec<_>>()
+            .join(".");
+        overrides.push((key, value.trim().to_string()));
+    }
+
+    Ok(overrides)
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgramAccountsPolicy {
+    #[default]
+    Allow,
+    Filtered,
+    Deny,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SolanaConfig {
+    #[serde(default = "default_confirmed_ttl_ms")]
+    pub confirmed_ttl_ms: u64,
+    #[serde(default = "default_finalized_ttl_ms")]
+    pub finalized_ttl_ms: u64,
+    #[serde(default)]
+    pub require_preflight: bool,
+    #[serde(default)]
+    pub program_accounts: ProgramAccountsPolicy,
+    #[serde(default = "default_max_slot_lag")]
+    pub max_slot_lag: u64,
+    #[serde(default = "default_slot_poll_interval_secs")]
+    pub slot_poll_interval_secs: u64,
+}
+
+fn default_confirmed_ttl_ms() -> u64 {
+    400
+}
+
+fn default_finalized_ttl_ms() -> u64 {
+    5000
+}
+
+fn default_max_slot_lag() -> u64 {
+    20
+}
+
+fn default_slot_poll_interval_secs() -> u64 {
+    2
+}
+
+impl Default for SolanaConfig {
+    fn default() -> Self {
+        Self {
+            confirmed_ttl_ms: default_confirmed_ttl_ms(),
+            finalized_ttl_ms: default_finalized_ttl_ms(),
+            require_preflight: false,
+            program_accounts: ProgramAccountsPolicy::default(),
+            max_slot_lag: default_max_slot_lag(),
+            slot_poll_interval_secs: default_slot_poll_interval_secs(),
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use std::net::SocketAddr;
+
+use axum::http::HeaderName;
+use url::Url;
+
+use super::{
+    policy::MethodPolicy,
+    settings::{
+        ChainKind, CredentialConfig, CredentialPlacement, DatabaseBackend, NetworkConfig, Settings,
+    },
+};
+use crate::routing::DEFAULT_UPSTREAM;
+
+const PLACEHOLDER_CLIENT_SECRET: &str = "your-secret-key-change-in-production";
+
+impl Settings {
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if self.server.host.is_empty() {
+            problems.push("server.host: must not be empty".to_string());
+        }
+
+        // auth 가 꺼져 있으면 DB 에 연결하지 않음
+        if self.auth.enabled && self.database.backend != DatabaseBackend::Memory {
+            if self.database.url.is_empty() {
+                problems.push("database.url: must not be empty".to_string());
+            }
+            if self.database.max_connections == 0 {
+                problems.push("database.max_connections: must be greater than 0".to_string());
+            }
+        }
+
+        if self.auth.enabled {
+            if self.auth.client_secret.as_deref() == Some(PLACEHOLDER_CLIENT_SECRET) {
+                problems.push(
+                    "auth.client_secret: still set to the placeholder value; set ARPC__AUTH__CLIENT_SECRET or ARPC__AUTH__CLIENT_SECRET_FILE".to_string(),
+                );
+            }
+            if self.auth.client_secret.as_deref() == Some("") {
+                problems.push("auth.client_secret: must not be empty".to_string());
+            }
+            if self.auth.key_expiration_days.is_some_and(|days| days <= 0) {
+                problems.push("auth.key_expiration_days: must be greater than 0".to_string());
+            }
+        }
+        if self.auth.timestamp_tolerance_secs <= 0 {
+            problems.push("auth.timestamp_tolerance_secs: must be greater than 0".to_string());
+        }
+
+        if self.usage.bucket_secs <= 0 {
+            problems.push("usage.bucket_secs: must be greater than 0".to_string());
+        }
+        if self.usage.flush_interval_secs == 0 {
+            problems.push("usage.flush_interval_secs: must be greater than 0".to_string());
+        }
+        if self.transactions.poll_interval_secs == 0 {
+            problems.push("transactions.poll_interval_secs: must be greater than 0".to_string());
+        }
+        if self.transactions.drop_after_secs <= 0 {
+            problems.push("transactions.drop_after_secs: must be greater than 0".to_string());
+        }
+        if self.transactions.recheck_dropped_secs < 0 {
+            problems.push("transactions.recheck_dropped_secs: must not be negative".to_string());
+        }
+        if self.transactions.batch_size <= 0 {
+            problems.push("transactions.batch_size: must be greater than 0".to_string());
+        }
+        if self.reload.interval_secs == 0 {
+            problems.push("reload.interval_secs: must be greater than 0".to_string());
+        }
+
+        if let Some(bind) = &self.admin.bind {
+            match bind_port(bind) {
+                None => problems.push(format!(
+                    "admin.bind: '{}' is not a valid host:port address (e.g. 127.0.0.1:9100, localhost:9100)",
+                    bind
+                )),
+                Some(port) if port == self.server.port => problems
+                    .push("admin.bind: must not use the same port as server.port".to_string()),
+                Some(_) => {}
+            }
+        }
+        if self.admin.token.as_deref() == Some("") {
+            problems.push("admin.token: must not be empty".to_string());
+        }
+
+        if self.chains.is_empty() {
+            problems.push("chains: no chains are configured".to_string());
+        }
+
+        for (chain_id, chain) in &self.chains {
+            let path = format!("chains.{}", chain_id);
+
+            if let Some(policy) = &chain.method_policy {
+                validate_policy(&format!("{}.method_policy", path), policy, &mut problems);
+            }
+            if chain.broadcast && chain.kind != Some(ChainKind::Evm) {
+                problems.push(format!(
+                    "{}.broadcast: only supported for kind = \"evm\"",
+                    path
+                ));
+            }
+            if let Some(pinning) = &chain.block_pinning {
+                if chain.kind != Some(ChainKind::Evm) {
+                    problems.push(format!(
+                        "{}.block_pinning: only supported for kind = \"evm\"",
+                        path
+                    ));
+                }
+                if pinning.poll_interval_secs == 0 {
+                    problems.push(format!(
+                        "{}.block_pinning.poll_interval_secs: must be greater than 0",
+                        path
+                    ));
+                }
+            }
+            if let Some(logs) = &chain.get_logs {
+                if chain.kind != Some(ChainKind::Evm) {
+                    problems.push(format!(
+                        "{}.get_logs: only supported for kind = \"evm\"",
+                        path
+                    ));
+                }
+                if logs.max_range == 0 {
+                    problems.push(format!(
+                        "{}.get_logs.max_range: must be greater than 0",
+                        path
+                    ));
+                }
+                if logs.concurrency == 0 {
+                    problems.push(format!(
+                        "{}.get_logs.concurrency: must be greater than 0",
+                        path
+                    ));
+                }
+            }
+            if let Some(solana) = &chain.solana {
+                if chain.kind != Some(ChainKind::Solana) {
+                    problems.push(format!(
+                        "{}.solana: only supported for kind = \"solana\"",
+                        path
+                    ));
+                }
+                if solana.slot_poll_interval_secs == 0 {
+                    problems.push(format!(
+                        "{}.solana.slot_poll_interval_secs: must be greater than 0",
+                        path
+                    ));
+                }
+            }
+            if let Some(cors) = &chain.cors {
+                for origin in &cors.allowed_origins {
+                    if origin != "*" && !is_http_url(origin) {
+                        problems.push(format!(
+                            "{}.cors.allowed_origins: '{}' must be \"*\" or an http(s) origin",
+                            path, origin
+                        ));
+                    }
+                }
+            }
+
+            if chain.testnets.contains_key("mainnet") {
+                problems.push(format!(
+                    "{}.testnets.mainnet: 'mainnet' is reserved for the mainnet network",
+                    path
+                ));
+            }
+
+            let networks = std::iter::once(("mainnet".to_string(), &chain.mainnet)).chain(
+                chain
+                    .testnets
+                    .iter()
+                    .map(|(id, network)| (format!("testnets.{}", id), network)),
+            );
+            for (network_id, network) in networks {
+                let network_path = format!("{}.{}", path, network_id);
+                if network.chain_id.is_some() && chain.kind != Some(ChainKind::Evm) {
+                    problems.push(format!(
+                        "{}.chain_id: only supported for kind = \"evm\"",
+                        network_path
+                    ));
+                }
+                validate_network(&network_path, network, &mut problems);
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            problems.sort();
+            Err(problems)
+        }
+    }
+}
+
+fn validate_network(path: &str, network: &NetworkConfig, problems: &mut Vec<String>) {
+    if !network.has_jsonrpc() && !network.has_rest() {
+        problems.push(format!("{}: neither jsonrpc_url nor rest_url is set", path));
+    }
+
+    if let Some(url) = &network.jsonrpc_url {
+        validate_url(&format!("{}.jsonrpc_url", path), url, problems);
+    }
+    if let Some(url) = &network.rest_url {
+        validate_url(&format!("{}.rest_url", path), url, problems);
+    }
+    if !network.allowed_paths.is_empty() && !network.has_rest() {
+        problems.push(format!(
+            "{}.allowed_paths: set but rest_url is missing",
+            path
+        ));
+    }
+
+    for url in &network.broadcast_urls {
+        validate_url(&format!("{}.broadcast_urls", path), url, problems);
+    }
+
+    if let Some(credential) = &network.credential {
+        validate_credential(&format!("{}.credential", path), credential, problems);
+    }
+    if let Some(policy) = &network.method_policy {
+        validate_policy(&format!("{}.method_policy", path), policy, problems);
+    }
+
+    for (name, group) in &network.upstreams {
+        let group_path = format!("{}.upstreams.{}", path, name);
+        if name == DEFAULT_UPSTREAM {
+            problems.push(format!(
+                "{}: '{}' is reserved",
+                group_path, DEFAULT_UPSTREAM
+            ));
+        }
+        if group.urls.is_empty() {
+            problems.push(format!("{}.urls: must not be empty", group_path));
+        }
+        for url in &group.urls {
+            validate_url(&format!("{}.urls", group_path), url, problems);
+        }
+        if let Some(credential) = &group.credential {
+            validate_credential(&format!("{}.credential", group_path), credential, problems);
+        }
+    }
+
+    for (index, route) in network.routes.iter().enumerate() {
+        let route_path = format!("{}.routes[{}]", path, index);
+        if route.methods.is_empty() {
+            problems.push(format!("{}.methods: must not be empty", route_path));
+        }
+        if route.upstream == DEFAULT_UPSTREAM {
+            if !network.has_jsonrpc() {
+                problems.push(format!(
+                    "{}.upstream: 'default' requires jsonrpc_url",
+                    route_path
+                ));
+            }
+        } else if !network.upstreams.contains_key(&route.upstream) {
+            problems.push(format!(
+                "{}.upstream: unknown upstream group '{}'",
+                route_path, route.upstream
+            ));
+        }
+    }
+}
+
+fn validate_url(path: &str, url: &str, problems: &mut Vec<String>) {
+    // `{api_key}` 자리표시자는 실제 키로 치환되므로 임의의 값으로 바꿔서 검사
+    let candidate = url.replace("{api_key}", "key");
+    match Url::parse(&candidate) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => {}
+        Ok(_) => problems.push(format!("{}: '{}' must be an http(s) URL", path, url)),
+        Err(e) => problems.push(format!("{}: '{}' is not a valid URL ({})", path, url, e)),
+    }
+}
+
+fn validate_credential(path: &str, credential: &CredentialConfig, problems: &mut Vec<String>) {
+    if credential.key.is_none() && credential.key_env.is_none() && credential.key_file.is_none() {
+        problems.push(format!(
+            "{}: one of key, key_env or key_file is required",
+            path
+        ));
+    }
+    if credential.key_env.is_some() && credential.key_file.is_some() {
+        problems.push(format!(
+            "{}: key_env and key_file are mutually exclusive",
+            path
+        ));
+    }
+    if let Some(error) = &credential.resolve_error {
+        problems.push(format!("{}: {}", path, error));
+    }
+
+    if credential.placement == CredentialPlacement::Header {
+        if let Some(name) = &credential.name {
+            if HeaderName::from_bytes(name.as_bytes()).is_err() {
+                problems.push(format!(
+                    "{}.name: '{}' is not a valid header name",
+                    path, name
+                ));
+            }
+        }
+    }
+}
+
+fn validate_policy(path: &str, policy: &MethodPolicy, problems: &mut Vec<String>) {
+    for pattern in policy.allow.iter().chain(&policy.deny) {
+        if pattern.is_empty() {
+            problems.push(format!("{}: empty method pattern", path));
+        }
+    }
+}
+
+// TcpListener::bind 와 같이 IP 주소와 호스트 이름을 모두 허용 (IPv6 는 [::1]:9100)
+fn bind_port(bind: &str) -> Option<u16> {
+    if let Ok(addr) = bind.parse::<SocketAddr>() {
+        return Some(addr.port());
+    }
+    let (host, port) = bind.rsplit_once(':')?;
+    let valid_host = !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.');
+    valid_host.then(|| port.parse().ok()).flatten()
+}
+
+fn is_http_url(value: &str) -> bool {
+    Url::parse(value).is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::{header, request::Parts, HeaderValue, Method, Uri},
+    middleware::Next,
+    response::Response,
+};
+use tower::{Layer, ServiceExt};
+use tower_http::cors::{AllowHeaders, AllowMethods, AllowOrigin, CorsLayer};
+
+use crate::{config::CorsConfig, state::AppState};
+
+const PREFLIGHT_MAX_AGE: Duration = Duration::from_secs(3600);
+
+// cors 가 설정된 체인의 실제 preflight (Origin + Access-Control-Request-Method) 만 프록시에서 응답.
+// 그 외 OPTIONS 는 다른 메서드와 마찬가지로 업스트림으로 전달
+pub async fn cors(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    if request.method() == Method::OPTIONS && !is_preflight(&state, &request) {
+        return next.run(request).await;
+    }
+
+    let response = cors_layer(&state).layer(next).oneshot(request).await;
+    match response {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
+fn cors_layer(state: &AppState) -> CorsLayer {
+    let state = state.clone();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::predicate(
+            move |origin: &HeaderValue, parts: &Parts| is_allowed(&state, origin, parts),
+        ))
+        .allow_methods(AllowMethods::mirror_request())
+        .allow_headers(AllowHeaders::mirror_request())
+        .max_age(PREFLIGHT_MAX_AGE)
+}
+
+fn is_preflight(state: &AppState, request: &Request) -> bool {
+    let headers = request.headers();
+    headers.contains_key(header::ORIGIN)
+        && headers.contains_key(header::ACCESS_CONTROL_REQUEST_METHOD)
+        && chain_cors(state, request.uri()).is_some()
+}
+
+fn is_allowed(state: &AppState, origin: &HeaderValue, parts: &Parts) -> bool {
+    let Ok(origin) = origin.to_str() else {
+        return false;
+    };
+
+    chain_cors(state, &parts.uri).is_some_and(|cors| cors.allows(origin))
+}
+
+fn chain_cors(state: &AppState, uri: &Uri) -> Option<CorsConfig> {
+    let chain = uri
+        .path()
+        .trim_start_matches('/')
+        .split('/')
+        .next()
+        .unwrap_or("");
+
+    state.settings().get_chain(chain)?.cors.clone()
+}
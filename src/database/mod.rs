@@ -0,0 +1,96 @@
+use std::{str::FromStr, sync::Arc};
+
+use sqlx::{
+    migrate::{MigrateError, Migrator},
+    postgres::PgPoolOptions,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    PgPool, SqlitePool,
+};
+
+use crate::{
+    auth::{
+        ApiKeyRepository, MemoryApiKeyRepository, PostgresApiKeyRepository, SqliteApiKeyRepository,
+    },
+    config::{DatabaseBackend, DatabaseConfig},
+};
+
+static POSTGRES_MIGRATIONS: Migrator = sqlx::migrate!("./migrations/postgres");
+static SQLITE_MIGRATIONS: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+#[derive(Clone)]
+pub enum Database {
+    Postgres(PgPool),
+    Sqlite(SqlitePool),
+    Memory,
+}
+
+impl Database {
+    pub async fn connect(config: &DatabaseConfig) -> Result<Self, sqlx::Error> {
+        match config.backend {
+            DatabaseBackend::Postgres => {
+                tracing::info!("Connecting to database...");
+                let pool = PgPoolOptions::new()
+                    .max_connections(config.max_connections)
+                    .connect(&config.url)
+                    .await?;
+                tracing::info!("Database connected");
+                Ok(Self::Postgres(pool))
+            }
+            DatabaseBackend::Sqlite => {
+                let options = SqliteConnectOptions::from_str(&config.url)?.create_if_missing(true);
+                let pool = SqlitePoolOptions::new()
+                    .max_connections(config.max_connections)
+                    .connect_with(options)
+                    .await?;
+                tracing::info!("SQLite database opened: {}", config.url);
+                Ok(Self::Sqlite(pool))
+            }
+            DatabaseBackend::Memory => {
+                tracing::warn!("Using in-memory API key store; keys are lost on restart");
+                Ok(Self::Memory)
+            }
+        }
+    }
+
+    // 적용 이력은 _sqlx_migrations 테이블에 기록되고, PostgreSQL 에서는 advisory lock 을
+    // 잡은 채로 실행되므로 여러 인스턴스가 동시에 시작해도 한 번만 적용됨
+    pub async fn migrate(&self) -> Result<(), MigrateError> {
+        match self {
+            Self::Postgres(pool) => POSTGRES_MIGRATIONS.run(pool).await,
+            Self::Sqlite(pool) => SQLITE_MIGRATIONS.run(pool).await,
+            Self::Memory => Ok(()),
+        }
+    }
+
+    pub fn latest_version(&self) -> Option<i64> {
+        let migrator = match self {
+            Self::Postgres(_) => &POSTGRES_MIGRATIONS,
+            Self::Sqlite(_) => &SQLITE_MIGRATIONS,
+            Self::Memory => return None,
+        };
+        migrator.iter().map(|migration| migration.version).max()
+    }
+
+    pub fn api_key_repo(&self) -> Arc<dyn ApiKeyRepository> {
+        match self {
+            Self::Postgres(pool) => Arc::new(PostgresApiKeyRepository::new(pool.clone())),
+            Self::Sqlite(pool) => Arc::new(SqliteApiKeyRepository::new(pool.clone())),
+            Self::Memory => Arc::new(MemoryApiKeyRepository::new()),
+        }
+    }
+
+    pub fn postgres(&self) -> Option<PgPool> {
+        match self {
+            Self::Postgres(pool) => Some(pool.clone()),
+            _ => None,
+        }
+    }
+
+    pub async fn close(&self) {
+        match self {
+            Self::Postgres(pool) => pool.close().await,
+            Self::Sqlite(pool) => pool.close().await,
+            Self::Memory => {}
+        }
+    }
+}
@@ -16,6 +16,24 @@ pub enum AppError {
     #[error("Protocol mismatch: {0}")]
     ProtocolMismatch(String),
 
+    #[error("Method not allowed: {0}")]
+    MethodNotAllowed(String),
+
+    #[error("Invalid path: {0}")]
+    InvalidPath(String),
+
+    #[error("Path not allowed: {0}")]
+    PathNotAllowed(String),
+
+    #[error("Invalid params: {0}")]
+    InvalidParams(String),
+
+    #[error("Invalid transaction: {0}")]
+    InvalidTransaction(String),
+
+    #[error("Limit exceeded: {0}")]
+    LimitExceeded(String),
+
     #[error("Provider error: {0}")]
     ProviderError(String),
 
@@ -29,6 +47,12 @@ impl AppError {
             AppError::ChainNotFound(_) => -32001,
             AppError::NetworkNotFound(_) => -32001,
             AppError::ProtocolMismatch(_) => -32002,
+            AppError::MethodNotAllowed(_) => -32601,
+            AppError::InvalidPath(_) => -32602,
+            AppError::PathNotAllowed(_) => -32004,
+            AppError::InvalidParams(_) => -32602,
+            AppError::InvalidTransaction(_) => -32602,
+            AppError::LimitExceeded(_) => -32005,
             AppError::ProviderError(_) => -32603,
             AppError::ParseError(_) => -32700,
         }
@@ -39,6 +63,12 @@ impl AppError {
             AppError::ChainNotFound(_) => StatusCode::BAD_REQUEST,
             AppError::NetworkNotFound(_) => StatusCode::BAD_REQUEST,
             AppError::ProtocolMismatch(_) => StatusCode::BAD_REQUEST,
+            AppError::MethodNotAllowed(_) => StatusCode::FORBIDDEN,
+            AppError::InvalidPath(_) => StatusCode::BAD_REQUEST,
+            AppError::PathNotAllowed(_) => StatusCode::FORBIDDEN,
+            AppError::InvalidParams(_) => StatusCode::BAD_REQUEST,
+            AppError::InvalidTransaction(_) => StatusCode::BAD_REQUEST,
+            AppError::LimitExceeded(_) => StatusCode::BAD_REQUEST,
             AppError::ProviderError(_) => StatusCode::BAD_GATEWAY,
             AppError::ParseError(_) => StatusCode::BAD_REQUEST,
         }
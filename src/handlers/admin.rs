@@ -0,0 +1,128 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{Duration, Utc};
+
+use crate::{
+    reload, state::AppState, transactions::model::TransactionQuery, usage::model::UsageQuery,
+};
+
+pub async fn usage_report(
+    State(state): State<AppState>,
+    Query(query): Query<UsageQuery>,
+) -> Response {
+    let Some(repo) = &state.usage_repo else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": {
+                    "code": -32601,
+                    "message": "Usage tracking requires the postgres database backend"
+                }
+            })),
+        )
+            .into_response();
+    };
+
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query.from.unwrap_or(to - Duration::days(1));
+
+    if from >= to {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": {
+                    "code": -32602,
+                    "message": "'from' must be earlier than 'to'"
+                }
+            })),
+        )
+            .into_response();
+    }
+
+    match repo.summarize(from, to, &query).await {
+        Ok(usage) => Json(serde_json::json!({
+            "from": from,
+            "to": to,
+            "usage": usage
+        }))
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to query usage: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": {
+                        "code": -32603,
+                        "message": "Failed to query usage"
+                    }
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+pub async fn transaction_report(
+    State(state): State<AppState>,
+    Query(query): Query<TransactionQuery>,
+) -> Response {
+    let Some(repo) = &state.transactions else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": {
+                    "code": -32601,
+                    "message": "Transaction tracking requires the postgres database backend"
+                }
+            })),
+        )
+            .into_response();
+    };
+
+    match repo.list(&query).await {
+        Ok(transactions) => Json(serde_json::json!({
+            "transactions": transactions
+        }))
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to query transactions: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": {
+                        "code": -32603,
+                        "message": "Failed to query transactions"
+                    }
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
+pub async fn reload_config(State(state): State<AppState>) -> Response {
+    match reload::reload(&state) {
+        Ok(()) => Json(serde_json::json!({
+            "status": "reloaded",
+            "chains": state.settings().supported_chains()
+        }))
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Configuration reload rejected: {}", e);
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(serde_json::json!({
+                    "error": {
+                        "code": -32603,
+                        "message": e.to_string()
+                    }
+                })),
+            )
+                .into_response()
+        }
+    }
+}
@@ -43,7 +43,9 @@ pub async fn register(
             .into_response();
     }
 
-    if let Some(client_secret) = &state.settings.auth.client_secret {
+    let settings = state.settings();
+
+    if let Some(client_secret) = &settings.auth.client_secret {
         let timestamp = match payload.timestamp {
             Some(ts) => ts,
             None => {
@@ -77,7 +79,7 @@ pub async fn register(
         };
 
         let now = Utc::now().timestamp();
-        let tolerance = state.settings.auth.timestamp_tolerance_secs;
+        let tolerance = settings.auth.timestamp_tolerance_secs;
         if (now - timestamp).abs() > tolerance {
             return (
                 StatusCode::UNAUTHORIZED,
@@ -123,8 +125,7 @@ pub async fn register(
             .into_response();
     }
 
-    let expires_at = state
-        .settings
+    let expires_at = settings
         .auth
         .key_expiration_days
         .map(|days| Utc::now() + Duration::days(days));
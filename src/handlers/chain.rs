@@ -5,7 +5,7 @@ use crate::{models::chain::ChainInfo, state::AppState};
 
 pub async fn list_chains(State(state): State<AppState>) -> Json<Value> {
     let chains: Vec<ChainInfo> = state
-        .settings
+        .settings()
         .chains
         .iter()
         .map(|(id, cfg)| {
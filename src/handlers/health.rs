@@ -14,6 +14,6 @@ pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponse>
     Json(HealthResponse {
         status: "ok".to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
-        supported_chains: state.settings.supported_chains(),
+        supported_chains: state.settings().supported_chains(),
     })
 }
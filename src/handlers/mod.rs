@@ -1,4 +1,6 @@
+pub mod admin;
 pub mod auth;
 pub mod chain;
 pub mod health;
+pub mod metrics;
 pub mod proxy;
@@ -1,138 +1,412 @@
+use std::time::{Duration, Instant};
+
 use axum::{
-    body::Body,
+    body::{Body, HttpBody},
     extract::{Path, State},
-    http::{Method, Uri},
+    http::{HeaderMap, Method, Uri},
     response::{IntoResponse, Response},
+    Extension, Json,
 };
+use serde_json::json;
+use tracing::Instrument;
+use uuid::Uuid;
 
 use crate::{
+    auth::ApiKey,
+    chains::{
+        self, bitcoin, cosmos,
+        evm::{self, LogBound},
+        solana::{self, Commitment},
+        stellar, DecodedTransaction, TransactionError,
+    },
+    config::{
+        ChainConfig, ChainKind, GetLogsConfig, NetworkConfig, ProgramAccountsPolicy, SolanaConfig,
+    },
     error::AppError,
     models::rpc::RpcRequest,
-    providers::{jsonrpc, rest},
+    providers::{
+        broadcast, esplora,
+        jsonrpc::{self, MethodNotFound},
+        logs, rest,
+    },
+    routing::{self, Upstream},
     state::AppState,
+    telemetry::{
+        access_log,
+        metrics::{self, Protocol, RequestLabels},
+    },
+    transactions::model::{NewTransaction, Submission},
 };
 
+const MAINNET: &str = "mainnet";
+// 프로토콜을 명시적으로 고르는 경로 접두어 (`/{chain}/rpc/...`, `/{chain}/{testnet}/rest/...`)
+const RPC_PREFIX: &str = "rpc";
+const REST_PREFIX: &str = "rest";
+// 경로 대신 네트워크를 지정하는 헤더와 쿼리 파라미터. 지정하면 경로 전체를 리소스 경로로 취급
+const NETWORK_HEADER: &str = "x-network";
+// 업스트림 API 의 network 파라미터와 겹치지 않도록 예약된 이름 사용
+const NETWORK_QUERY: &str = "arpc_network";
+
+struct Target<'a> {
+    chain_id: &'a str,
+    chain: &'a ChainConfig,
+    network_id: &'a str,
+    network: &'a NetworkConfig,
+    api_key: Option<ApiKey>,
+}
+
+impl Target<'_> {
+    fn api_key_id(&self) -> Option<Uuid> {
+        self.api_key.as_ref().map(|key| key.id)
+    }
+
+    fn labels(&self, protocol: Protocol) -> RequestLabels {
+        RequestLabels {
+            chain: self.chain_id.to_string(),
+            network: self.network_id.to_string(),
+            protocol,
+            method: String::new(),
+            upstream: routing::DEFAULT_UPSTREAM.to_string(),
+        }
+    }
+
+    fn finish(
+        &self,
+        state: &AppState,
+        labels: &RequestLabels,
+        started: Instant,
+        response: &Response,
+    ) {
+        let status = response.status().as_u16();
+        let elapsed = started.elapsed();
+
+        let bytes = response.body().size_hint().exact();
+
+        metrics::record_request(labels, status, elapsed);
+
+        if let (Some(usage), Some(api_key_id)) = (&state.usage, self.api_key_id()) {
+            usage.record(
+                api_key_id,
+                &labels.chain,
+                &labels.network,
+                &labels.method,
+                status >= 400,
+                bytes.unwrap_or_default(),
+            );
+        }
+
+        if state.settings().logging.access_log {
+            access_log::record(labels, self.api_key_id(), status, elapsed, bytes);
+        }
+    }
+}
+
 pub async fn proxy_mainnet(
     State(state): State<AppState>,
+    api_key: Option<Extension<ApiKey>>,
     method: Method,
     Path(chain): Path<String>,
+    headers: HeaderMap,
     uri: Uri,
     body: Body,
 ) -> Response {
-    let chain_config = match state.settings.get_chain(&chain) {
+    let settings = state.settings();
+    let chain_config = match settings.get_chain(&chain) {
         Some(cfg) => cfg,
         None => return AppError::ChainNotFound(chain).into_response(),
     };
 
-    let network_config = &chain_config.mainnet;
+    let (selected, query) = select_network(&headers, uri.query());
+    let network_id = selected.as_deref().unwrap_or(MAINNET);
+    let Some(network) = chain_config.network(network_id) else {
+        return AppError::NetworkNotFound(network_id.to_string()).into_response();
+    };
 
-    if method == Method::POST && network_config.has_jsonrpc() {
-        handle_jsonrpc(
-            &state,
-            &chain,
-            network_config.jsonrpc_url.as_ref().unwrap(),
-            body,
-        )
-        .await
-    } else if network_config.has_rest() {
-        let query = uri.query();
-        handle_rest(
-            &state,
-            &chain,
-            network_config.rest_url.as_ref().unwrap(),
-            network_config.api_key.as_deref(),
-            method,
-            "",
-            query,
-            body,
-        )
-        .await
-    } else {
-        AppError::ProtocolMismatch(format!(
-            "Chain '{}' mainnet has no supported endpoints",
-            chain
-        ))
-        .into_response()
-    }
+    let target = Target {
+        chain_id: &chain,
+        chain: chain_config,
+        network_id,
+        network,
+        api_key: api_key.map(|Extension(key)| key),
+    };
+
+    dispatch(&state, &target, method, "", query.as_deref(), body).await
 }
 
+// 네트워크는 X-Network 헤더, ?arpc_network=, 첫 경로 세그먼트 ("mainnet" 또는 테스트넷 이름) 순으로 결정.
+// 어느 것도 아니면 기존처럼 mainnet 의 경로로 취급
 pub async fn proxy_with_path(
     State(state): State<AppState>,
+    api_key: Option<Extension<ApiKey>>,
     method: Method,
     Path((chain, path)): Path<(String, String)>,
+    headers: HeaderMap,
     uri: Uri,
     body: Body,
 ) -> Response {
-    let chain_config = match state.settings.get_chain(&chain) {
+    let settings = state.settings();
+    let chain_config = match settings.get_chain(&chain) {
         Some(cfg) => cfg,
         None => return AppError::ChainNotFound(chain).into_response(),
     };
 
-    let first_segment = path.split('/').next().unwrap_or("");
+    let (selected, query) = select_network(&headers, uri.query());
+    let (network_id, path) = match selected.as_deref() {
+        Some(network_id) => (network_id, path.as_str()),
+        None => {
+            let (first_segment, rest_path) = split_segment(&path);
+            if first_segment == MAINNET || chain_config.testnets.contains_key(first_segment) {
+                (first_segment, rest_path)
+            } else if !first_segment.is_empty()
+                && !chain_config.mainnet.has_rest()
+                && !is_rpc_path(chain_config, &path)
+            {
+                // REST 경로일 수 없으므로 알 수 없는 네트워크
+                return AppError::NetworkNotFound(first_segment.to_string()).into_response();
+            } else {
+                (MAINNET, path.as_str())
+            }
+        }
+    };
+    let Some(network) = chain_config.network(network_id) else {
+        return AppError::NetworkNotFound(network_id.to_string()).into_response();
+    };
 
-    if let Some(testnet_config) = chain_config.testnets.get(first_segment) {
-        let rest_path = path.splitn(2, '/').nth(1).unwrap_or("");
+    let target = Target {
+        chain_id: &chain,
+        chain: chain_config,
+        network_id,
+        network,
+        api_key: api_key.map(|Extension(key)| key),
+    };
 
-        if method == Method::POST && rest_path.is_empty() && testnet_config.has_jsonrpc() {
-            return handle_jsonrpc(
-                &state,
-                &chain,
-                testnet_config.jsonrpc_url.as_ref().unwrap(),
-                body,
-            )
-            .await;
-        }
-
-        if testnet_config.has_rest() {
-            let query = uri.query();
-            return handle_rest(
-                &state,
-                &chain,
-                testnet_config.rest_url.as_ref().unwrap(),
-                testnet_config.api_key.as_deref(),
-                method,
-                rest_path,
-                query,
-                body,
-            )
-            .await;
+    dispatch(&state, &target, method, path, query.as_deref(), body).await
+}
+
+// 헤더 또는 쿼리로 지정한 네트워크와 업스트림에 보낼 쿼리.
+// 쿼리로 지정한 경우에만 arpc_network 파라미터를 제거
+fn select_network(headers: &HeaderMap, query: Option<&str>) -> (Option<String>, Option<String>) {
+    if let Some(network) = headers
+        .get(NETWORK_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        return (Some(network.to_string()), query.map(str::to_string));
+    }
+
+    let Some(query) = query else {
+        return (None, None);
+    };
+    // 예약된 쌍만 빼고 나머지 쿼리는 받은 그대로 둠
+    let Some((index, (_, _, network))) = rest::raw_pairs(query)
+        .enumerate()
+        .find(|(_, (_, name, _))| name == NETWORK_QUERY)
+    else {
+        return (None, Some(query.to_string()));
+    };
+    let remaining = rest::raw_pairs(query)
+        .enumerate()
+        .filter(|(i, _)| *i != index)
+        .map(|(_, (pair, _, _))| pair)
+        .collect::<Vec<_>>()
+        .join("&");
+    (Some(network), Some(remaining))
+}
+
+// JSON-RPC 업스트림으로 가는 경로 (`rpc/...` 또는 cosmos 의 Tendermint RPC 경로)
+fn is_rpc_path(chain: &ChainConfig, path: &str) -> bool {
+    split_segment(path).0 == RPC_PREFIX
+        || (chain.kind == Some(ChainKind::Cosmos) && cosmos::rpc_method(path).is_some())
+}
+
+// 네트워크 아래 경로로 프로토콜 선택.
+// `rpc/...` 는 JSON-RPC 업스트림, `rest/...` 는 REST 업스트림으로 명시적으로 보냄.
+// 접두어가 없으면 경로 없는 POST 는 JSON-RPC, cosmos 의 Tendermint RPC 경로는 JSON-RPC 업스트림, 나머지는 REST
+async fn dispatch(
+    state: &AppState,
+    target: &Target<'_>,
+    method: Method,
+    path: &str,
+    query: Option<&str>,
+    body: Body,
+) -> Response {
+    let network = target.network;
+    let (first_segment, rest_path) = split_segment(path);
+
+    // 접두어가 가리키는 엔드포인트가 있으면 접두어를 떼고 그쪽으로 보냄.
+    // 없으면 "rpc", "rest" 도 일반 경로 세그먼트로 취급
+    match first_segment {
+        RPC_PREFIX if network.has_jsonrpc() => {
+            return handle_rpc(state, target, method, rest_path, query, body).await;
         }
+        REST_PREFIX if network.has_rest() => {
+            return handle_rest(state, target, method, rest_path, query, body).await;
+        }
+        _ => {}
+    }
+
+    if method == Method::POST && path.is_empty() && target.chain.serves_jsonrpc(network) {
+        return handle_jsonrpc(state, target, body).await;
+    }
+
+    if target.chain.kind == Some(ChainKind::Cosmos)
+        && network.has_jsonrpc()
+        && cosmos::rpc_method(path).is_some()
+    {
+        return handle_rpc(state, target, method, path, query, body).await;
+    }
+
+    if network.has_rest() {
+        return handle_rest(state, target, method, path, query, body).await;
+    }
+
+    AppError::ProtocolMismatch(format!(
+        "Network '{}' has no supported endpoints for this request",
+        target.network_id
+    ))
+    .into_response()
+}
+
+fn split_segment(path: &str) -> (&str, &str) {
+    path.split_once('/').unwrap_or((path, ""))
+}
+
+// JSON-RPC 업스트림으로 가는 요청. 경로 없는 POST 는 JSON-RPC, 그 외는 URI 형식 (`GET /status`)
+async fn handle_rpc(
+    state: &AppState,
+    target: &Target<'_>,
+    method: Method,
+    path: &str,
+    query: Option<&str>,
+    body: Body,
+) -> Response {
+    if method == Method::POST && path.is_empty() {
+        return handle_jsonrpc(state, target, body).await;
+    }
+
+    let started = Instant::now();
+    let mut labels = target.labels(Protocol::JsonRpc);
+
+    let response = forward_rpc_uri(state, target, method, path, query, body, &mut labels).await;
+
+    target.finish(state, &labels, started, &response);
+    response
+}
+
+async fn forward_rpc_uri(
+    state: &AppState,
+    target: &Target<'_>,
+    method: Method,
+    path: &str,
+    query: Option<&str>,
+    body: Body,
+    labels: &mut RequestLabels,
+) -> Response {
+    let (chain, network) = (target.chain_id, target.network);
 
+    tracing::info!(
+        chain = %chain,
+        method = %method,
+        path = %path,
+        "Incoming RPC URI request"
+    );
+
+    let name = split_segment(path.trim_start_matches('/')).0;
+    if !target.chain.is_method_allowed(network, name) {
+        tracing::warn!(chain = %chain, method = %name, "RPC method not allowed");
+        labels.method = metrics::OTHER_METHOD.to_string();
+        return AppError::MethodNotAllowed(name.to_string()).into_response();
+    }
+    // Tendermint RPC 로 알려진 경로만 메서드 레이블로 사용
+    labels.method = match cosmos::rpc_method(name) {
+        Some(name) => name.to_string(),
+        None => metrics::OTHER_METHOD.to_string(),
+    };
+
+    let Some(upstream) = routing::upstream(network, routing::DEFAULT_UPSTREAM) else {
         return AppError::ProtocolMismatch(format!(
-            "Network '{}' has no supported endpoints for this request",
-            first_segment
+            "Network '{}' has no JSON-RPC endpoint",
+            network.name
         ))
         .into_response();
-    }
+    };
+    labels.upstream = upstream.name.to_string();
+
+    // 연결에 실패하면 다음 URL 로 재시도하므로 본문을 먼저 읽어 둠
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(b) => b,
+        Err(e) => return AppError::ParseError(e.to_string()).into_response(),
+    };
 
-    let network_config = &chain_config.mainnet;
+    let mut last_error =
+        AppError::ProviderError(format!("Upstream '{}' has no endpoints", upstream.name));
+    for base_url in state.health.order(&upstream.urls) {
+        let url = match rest::build_rpc_url(base_url, upstream.credential, path, query) {
+            Ok(url) => url,
+            Err(err) => {
+                tracing::warn!(chain = %chain, path = %path, error = %err, "Rejected RPC path");
+                return err.into_response();
+            }
+        };
 
-    if network_config.has_rest() {
-        let query = uri.query();
-        return handle_rest(
-            &state,
-            &chain,
-            network_config.rest_url.as_ref().unwrap(),
-            network_config.api_key.as_deref(),
-            method,
-            &path,
-            query,
+        let body = Body::from(bytes.clone());
+        match rest::forward(
+            &state.http_client,
+            method.clone(),
+            url,
+            upstream.credential,
             body,
         )
-        .await;
+        .await
+        {
+            Ok(response) => {
+                if response.status().is_server_error() {
+                    state.health.record_failure(base_url);
+                } else {
+                    state.health.record_success(base_url);
+                }
+                return response;
+            }
+            Err(err) => {
+                tracing::warn!(upstream = %upstream.name, error = ?err, "Upstream endpoint failed");
+                state.health.record_failure(base_url);
+                last_error = err;
+            }
+        }
+    }
+
+    tracing::error!(error = ?last_error, "RPC URI proxy failed");
+    last_error.into_response()
+}
+
+async fn handle_jsonrpc(state: &AppState, target: &Target<'_>, body: Body) -> Response {
+    let started = Instant::now();
+    let mut labels = target.labels(Protocol::JsonRpc);
+
+    let response = forward_jsonrpc(state, target, body, &mut labels).await;
+    if response.extensions().get::<MethodNotFound>().is_some() {
+        labels.method = metrics::OTHER_METHOD.to_string();
     }
 
-    AppError::ProtocolMismatch(format!("Chain '{}' mainnet has no REST endpoint", chain))
-        .into_response()
+    target.finish(state, &labels, started, &response);
+    response
 }
 
-async fn handle_jsonrpc(state: &AppState, chain: &str, url: &str, body: Body) -> Response {
+async fn forward_jsonrpc(
+    state: &AppState,
+    target: &Target<'_>,
+    body: Body,
+    labels: &mut RequestLabels,
+) -> Response {
+    let (chain, network) = (target.chain_id, target.network);
+
     let bytes = match axum::body::to_bytes(body, usize::MAX).await {
         Ok(b) => b,
         Err(e) => return AppError::ParseError(e.to_string()).into_response(),
     };
 
-    let payload: RpcRequest = match serde_json::from_slice(&bytes) {
+    let mut payload: RpcRequest = match serde_json::from_slice(&bytes) {
         Ok(p) => p,
         Err(e) => return AppError::ParseError(e.to_string()).into_response(),
     };
@@ -144,25 +418,317 @@ async fn handle_jsonrpc(state: &AppState, chain: &str, url: &str, body: Body) ->
         "Incoming JSON-RPC request"
     );
 
-    match jsonrpc::forward(&state.http_client, url, &payload).await {
-        Ok(response) => response,
-        Err(err) => {
-            tracing::error!(error = ?err, "JSON-RPC proxy failed");
-            err.into_response()
+    if !target.chain.is_method_allowed(network, &payload.method) {
+        tracing::warn!(chain = %chain, method = %payload.method, "JSON-RPC method not allowed");
+        labels.method = metrics::OTHER_METHOD.to_string();
+        return AppError::MethodNotAllowed(payload.method).into_response();
+    }
+    labels.method = payload.method.clone();
+
+    if let (Some(ChainKind::Solana), Some(config)) = (target.chain.kind, &target.chain.solana) {
+        if let Err(err) = check_solana_request(config, &payload) {
+            tracing::warn!(chain = %chain, method = %payload.method, error = %err, "Rejected Solana request");
+            return err.into_response();
+        }
+    }
+
+    let transaction = match chains::decode_jsonrpc(target.chain.kind, &payload)
+        .and_then(|tx| tx.map(|tx| inspect_transaction(target, tx)).transpose())
+    {
+        Ok(tx) => tx,
+        Err(err) => return reject_transaction(target, err),
+    };
+
+    let response = if target.chain.broadcast && payload.method == evm::SEND_RAW_TRANSACTION {
+        labels.upstream = routing::BROADCAST_UPSTREAM.to_string();
+        let targets = routing::broadcast_targets(network);
+        match broadcast::broadcast(
+            &state.http_client,
+            &state.health,
+            &state.tasks,
+            targets,
+            &payload,
+        )
+        .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                tracing::error!(error = ?err, "Transaction broadcast failed");
+                err.into_response()
+            }
+        }
+    } else if target.chain.kind == Some(ChainKind::Bitcoin) && !network.has_jsonrpc() {
+        labels.upstream = routing::ESPLORA_UPSTREAM.to_string();
+        match esplora::call(&state.http_client, network, &payload).await {
+            Ok(response) => response,
+            Err(err) => {
+                tracing::error!(error = ?err, "Esplora adapter failed");
+                err.into_response()
+            }
+        }
+    } else {
+        let cache_key = solana_cache_key(target, &payload);
+        if let Some((key, _)) = &cache_key {
+            let span = tracing::info_span!(
+                "cache.lookup",
+                method = %payload.method,
+                hit = tracing::field::Empty,
+            );
+            let cached = span.in_scope(|| state.cache.get(key));
+            span.record("hit", cached.is_some());
+            if let Some(result) = cached {
+                labels.upstream = routing::CACHE_UPSTREAM.to_string();
+                return Json(json!({ "jsonrpc": "2.0", "id": payload.id, "result": result }))
+                    .into_response();
+            }
+        }
+
+        let Some(mut upstream) = routing::select_jsonrpc(target.chain.kind, network, &payload)
+        else {
+            return AppError::ProtocolMismatch(format!(
+                "Network '{}' has no JSON-RPC endpoint",
+                network.name
+            ))
+            .into_response();
+        };
+
+        if let Some(pinning) = target
+            .chain
+            .block_pinning
+            .as_ref()
+            .filter(|_| target.chain.kind == Some(ChainKind::Evm))
+        {
+            if let Some(number) =
+                routing::pin(&state.blocks, network, pinning, &mut payload, &mut upstream)
+            {
+                labels.upstream = routing::PINNED_UPSTREAM.to_string();
+                return Json(json!({
+                    "jsonrpc": "2.0",
+                    "id": payload.id,
+                    "result": format!("0x{:x}", number)
+                }))
+                .into_response();
+            }
+        }
+
+        if let (Some(ChainKind::Solana), Some(config)) = (target.chain.kind, &target.chain.solana) {
+            routing::prefer_synced(&state.blocks, network, config, &mut upstream);
+        }
+
+        tracing::debug!(upstream = %upstream.name, method = %payload.method, "Selected upstream");
+        labels.upstream = upstream.name.to_string();
+
+        let split_range = match &target.chain.get_logs {
+            Some(config) if payload.method == evm::GET_LOGS => {
+                match get_logs_range(state, target, &upstream, &payload, config).await {
+                    Ok(range) => range,
+                    Err(err) => {
+                        tracing::warn!(chain = %chain, error = %err, "Rejected eth_getLogs range");
+                        return err.into_response();
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let result = match (split_range, &target.chain.get_logs) {
+            (Some(range), Some(config)) => {
+                logs::split(
+                    &state.http_client,
+                    &state.health,
+                    &upstream,
+                    &payload,
+                    range,
+                    config,
+                )
+                .await
+            }
+            _ => {
+                jsonrpc::forward_upstream(&state.http_client, &state.health, &upstream, &payload)
+                    .await
+            }
+        };
+        match (result, cache_key) {
+            (Ok(response), Some((key, ttl))) => cache_response(state, key, ttl, response).await,
+            (Ok(response), None) => response,
+            (Err(err), _) => {
+                tracing::error!(error = ?err, "JSON-RPC proxy failed");
+                err.into_response()
+            }
+        }
+    };
+
+    match transaction {
+        Some(tx) => {
+            record_transaction(
+                state,
+                target,
+                &labels.upstream,
+                Protocol::JsonRpc,
+                Some(tx),
+                response,
+            )
+            .await
+        }
+        None => response,
+    }
+}
+
+// 전송 전 시뮬레이션 강제와 필터 없는 getProgramAccounts 제한
+fn check_solana_request(config: &SolanaConfig, request: &RpcRequest) -> Result<(), AppError> {
+    match request.method.as_str() {
+        solana::SEND_TRANSACTION
+            if config.require_preflight && solana::skips_preflight(&request.params) =>
+        {
+            Err(AppError::InvalidParams(
+                "skipPreflight is not allowed on this network".to_string(),
+            ))
+        }
+        solana::GET_PROGRAM_ACCOUNTS => match config.program_accounts {
+            ProgramAccountsPolicy::Allow => Ok(()),
+            ProgramAccountsPolicy::Filtered if solana::has_filters(&request.params) => Ok(()),
+            ProgramAccountsPolicy::Filtered => Err(AppError::InvalidParams(
+                "getProgramAccounts requires at least one filter".to_string(),
+            )),
+            ProgramAccountsPolicy::Deny => Err(AppError::MethodNotAllowed(request.method.clone())),
+        },
+        _ => Ok(()),
+    }
+}
+
+// commitment 별 TTL 로 캐시할 Solana 조회 요청의 키. processed 는 캐시하지 않음
+fn solana_cache_key(target: &Target<'_>, request: &RpcRequest) -> Option<(String, Duration)> {
+    if target.chain.kind != Some(ChainKind::Solana) || !solana::is_cacheable(&request.method) {
+        return None;
+    }
+
+    let config = target.chain.solana.as_ref()?;
+    let ttl_ms = match solana::commitment(&request.params) {
+        Commitment::Processed => return None,
+        Commitment::Confirmed => config.confirmed_ttl_ms,
+        Commitment::Finalized => config.finalized_ttl_ms,
+    };
+    if ttl_ms == 0 {
+        return None;
+    }
+
+    let params = serde_json::to_string(&request.params).ok()?;
+    let key = format!(
+        "{}:{}:{}:{}",
+        target.chain_id, target.network_id, request.method, params
+    );
+    Some((key, Duration::from_millis(ttl_ms)))
+}
+
+// 에러가 아닌 응답의 result 만 캐시
+async fn cache_response(
+    state: &AppState,
+    key: String,
+    ttl: Duration,
+    response: Response,
+) -> Response {
+    if !response.status().is_success() {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(b) => b,
+        Err(e) => return AppError::ProviderError(e.to_string()).into_response(),
+    };
+
+    if let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(&bytes) {
+        let failed = value.get("error").is_some_and(|error| !error.is_null());
+        if let Some(result) = value.get_mut("result").filter(|_| !failed) {
+            state.cache.insert(key, result.take(), ttl);
+        }
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+// 나눠서 조회해야 하는 eth_getLogs 범위. 허용 한도를 넘으면 에러
+async fn get_logs_range(
+    state: &AppState,
+    target: &Target<'_>,
+    upstream: &Upstream<'_>,
+    request: &RpcRequest,
+    config: &GetLogsConfig,
+) -> Result<Option<(u64, u64)>, AppError> {
+    let Some((from, to)) = evm::log_bounds(&request.params) else {
+        return Ok(None);
+    };
+
+    let head = if from == LogBound::Latest || to == LogBound::Latest {
+        let tracked = target.chain.block_pinning.as_ref().and_then(|pinning| {
+            state.blocks.highest(
+                &routing::jsonrpc_urls(target.network),
+                routing::max_age(pinning.poll_interval_secs),
+            )
+        });
+        match tracked {
+            Some(head) => head,
+            None => logs::block_number(&state.http_client, &state.health, upstream).await?,
         }
+    } else {
+        0
+    };
+    let mut resolved = [0; 2];
+    for (slot, bound) in resolved.iter_mut().zip([from, to]) {
+        *slot = match bound {
+            LogBound::Latest => head,
+            LogBound::Tagged(tag) => {
+                logs::tagged_block_number(&state.http_client, &state.health, upstream, tag).await?
+            }
+            LogBound::Number(number) => number,
+        };
+    }
+
+    let [from, to] = resolved;
+    if from > to {
+        return Ok(None);
     }
+
+    let range = to - from + 1;
+    let limit = config.limit_for(target.api_key.as_ref().map(|key| key.device_id.as_str()));
+    if range > limit {
+        return Err(AppError::LimitExceeded(format!(
+            "eth_getLogs range of {} blocks exceeds the limit of {}",
+            range, limit
+        )));
+    }
+
+    Ok((range > config.max_range).then_some((from, to)))
 }
 
 async fn handle_rest(
     state: &AppState,
-    chain: &str,
-    base_url: &str,
-    api_key: Option<&str>,
+    target: &Target<'_>,
     method: Method,
     path: &str,
     query: Option<&str>,
     body: Body,
 ) -> Response {
+    let started = Instant::now();
+    let mut labels = target.labels(Protocol::Rest);
+    labels.method = method.to_string();
+
+    let response = forward_rest(state, target, method, path, query, body).await;
+
+    target.finish(state, &labels, started, &response);
+    response
+}
+
+async fn forward_rest(
+    state: &AppState,
+    target: &Target<'_>,
+    method: Method,
+    path: &str,
+    query: Option<&str>,
+    body: Body,
+) -> Response {
+    let (chain, network) = (target.chain_id, target.network);
+
     tracing::info!(
         chain = %chain,
         method = %method,
@@ -170,19 +736,42 @@ async fn handle_rest(
         "Incoming REST request"
     );
 
-    let body = if method == Method::GET || method == Method::DELETE {
-        None
-    } else {
-        Some(body)
+    let is_post = method == Method::POST;
+    let (body, transaction, submits) = match target.chain.kind {
+        Some(ChainKind::Bitcoin)
+            if is_post && path.trim_matches('/') == bitcoin::BROADCAST_PATH =>
+        {
+            let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+                Ok(b) => b,
+                Err(e) => return AppError::ParseError(e.to_string()).into_response(),
+            };
+            let decoded = std::str::from_utf8(&bytes)
+                .map_err(|e| TransactionError::Encoding(e.to_string()))
+                .and_then(bitcoin::decode_transaction)
+                .and_then(|tx| inspect_transaction(target, tx));
+            match decoded {
+                Ok(tx) => (Body::from(bytes), Some(tx), true),
+                Err(err) => return reject_transaction(target, err),
+            }
+        }
+        // Stellar 트랜잭션은 디코딩하지 않고 Horizon 응답의 해시를 기록
+        Some(ChainKind::Stellar) if is_post && stellar::is_submit_path(path) => (body, None, true),
+        _ => (body, None, false),
     };
 
-    match rest::forward(
+    let url = match rest::build_url(network, path, query) {
+        Ok(url) => url,
+        Err(err) => {
+            tracing::warn!(chain = %chain, path = %path, error = %err, "Rejected REST path");
+            return err.into_response();
+        }
+    };
+
+    let response = match rest::forward(
         &state.http_client,
-        base_url,
         method,
-        path,
-        query,
-        api_key,
+        url,
+        network.credential.as_ref(),
         body,
     )
     .await
@@ -192,5 +781,97 @@ async fn handle_rest(
             tracing::error!(error = ?err, "REST proxy failed");
             err.into_response()
         }
+    };
+
+    if submits {
+        record_transaction(
+            state,
+            target,
+            routing::DEFAULT_UPSTREAM,
+            Protocol::Rest,
+            transaction,
+            response,
+        )
+        .await
+    } else {
+        response
     }
 }
+
+fn inspect_transaction(
+    target: &Target<'_>,
+    tx: DecodedTransaction,
+) -> Result<DecodedTransaction, TransactionError> {
+    tx.check_chain_id(target.network.chain_id)?;
+    tracing::info!(
+        chain = %target.chain_id,
+        network = %target.network_id,
+        hash = %tx.hash,
+        sender = tx.sender.as_deref().unwrap_or("-"),
+        chain_id = ?tx.chain_id,
+        "Forwarding signed transaction"
+    );
+    Ok(tx)
+}
+
+fn reject_transaction(target: &Target<'_>, err: TransactionError) -> Response {
+    tracing::warn!(
+        chain = %target.chain_id,
+        network = %target.network_id,
+        error = %err,
+        "Rejected transaction"
+    );
+    AppError::InvalidTransaction(err.to_string()).into_response()
+}
+
+// 전송 결과를 감사 로그에 기록. 기록 실패는 응답에 영향을 주지 않음
+async fn record_transaction(
+    state: &AppState,
+    target: &Target<'_>,
+    upstream: &str,
+    protocol: Protocol,
+    tx: Option<DecodedTransaction>,
+    response: Response,
+) -> Response {
+    let Some(repo) = &state.transactions else {
+        return response;
+    };
+
+    let (parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(b) => b,
+        Err(e) => return AppError::ProviderError(e.to_string()).into_response(),
+    };
+
+    let submission = match protocol {
+        Protocol::JsonRpc => Submission::from_jsonrpc(&bytes),
+        Protocol::Rest => Submission::from_rest(parts.status, &bytes),
+    };
+    let (hash, sender) = match tx {
+        Some(tx) => (Some(tx.hash), tx.sender),
+        None => (submission.hash, None),
+    };
+    let record = NewTransaction {
+        api_key_id: target.api_key_id(),
+        chain: target.chain_id.to_string(),
+        network: target.network_id.to_string(),
+        hash,
+        sender,
+        upstream: upstream.to_string(),
+        accepted: submission.accepted,
+        error: submission.error,
+    };
+
+    // 응답을 지연시키지 않도록 기록은 백그라운드에서 (종료 시 완료를 기다림)
+    let repo = repo.clone();
+    state.tasks.spawn(
+        async move {
+            if let Err(e) = repo.insert(&record).await {
+                tracing::error!("Failed to record transaction: {:?}", e);
+            }
+        }
+        .in_current_span(),
+    );
+
+    Response::from_parts(parts, Body::from(bytes))
+}
@@ -1,7 +1,18 @@
 pub mod auth;
+pub mod cache;
+pub mod chains;
+pub mod cli;
 pub mod config;
+pub mod cors;
+pub mod database;
 pub mod error;
 pub mod handlers;
 pub mod models;
 pub mod providers;
+pub mod reload;
+pub mod routing;
+pub mod shutdown;
 pub mod state;
+pub mod telemetry;
+pub mod transactions;
+pub mod usage;
@@ -1,24 +1,54 @@
+use std::{collections::HashMap, future::IntoFuture, time::Duration};
+
+use clap::Parser;
+
 use axum::{
     middleware,
     routing::{any, get, post},
     Router,
 };
-use sqlx::postgres::PgPoolOptions;
-use tower_http::trace::TraceLayer;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tokio_util::sync::CancellationToken;
+use tower_http::{
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
+};
 
-use arpc_proxy::{auth::auth_middleware, config::Settings, handlers, state::AppState};
+use arpc_proxy::{
+    auth::{admin_middleware, auth_middleware},
+    cli::{self, Cli, Command, ConfigCommand},
+    config::Settings,
+    cors,
+    database::Database,
+    handlers, reload, routing, shutdown,
+    state::AppState,
+    telemetry, transactions,
+};
 
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::new(
-            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
-        ))
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    let cli = Cli::parse();
+    let command = match cli.command {
+        Some(command) => Some(command),
+        None if cli.check_config => Some(Command::Config(ConfigCommand::Check)),
+        None => None,
+    };
+    if let Some(command) = command {
+        if let Err(e) = cli::run(command).await {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let tracing_guard = telemetry::trace::init();
 
     let settings = Settings::load().expect("Failed to load settings");
+    if let Err(problems) = settings.validate() {
+        for problem in &problems {
+            tracing::error!("Invalid configuration: {}", problem);
+        }
+        std::process::exit(1);
+    }
     let addr = settings.server_addr();
     let auth_enabled = settings.auth.enabled;
 
@@ -48,29 +78,52 @@ async fn main() {
         );
     }
 
-    tracing::info!("Connecting to database...");
-    let pool = PgPoolOptions::new()
-        .max_connections(settings.database.max_connections)
-        .connect(&settings.database.url)
-        .await
-        .expect("Failed to connect to database");
-    tracing::info!("Database connected");
+    // auth 가 꺼져 있으면 DB 에 연결하지 않고 (사용되지 않는) 메모리 저장소를 사용
+    let database = if auth_enabled {
+        Database::connect(&settings.database)
+            .await
+            .expect("Failed to connect to database")
+    } else {
+        Database::Memory
+    };
+
+    if auth_enabled && settings.database.auto_migrate {
+        database
+            .migrate()
+            .await
+            .expect("Failed to run database migrations");
+        tracing::info!("Database migrations applied");
+    }
+
+    let metrics = settings.metrics.enabled.then(telemetry::metrics::install);
+    let state = AppState::new(
+        settings.clone(),
+        database.postgres(),
+        database.api_key_repo(),
+        metrics,
+    );
 
-    let state = AppState::new(settings.clone(), pool);
+    if auth_enabled && settings.usage.enabled && state.usage.is_none() {
+        tracing::warn!("Usage tracking requires the postgres database backend; disabled");
+    }
+    if auth_enabled && settings.transactions.enabled && state.transactions.is_none() {
+        tracing::warn!("Transaction tracking requires the postgres database backend; disabled");
+    }
 
-    state
-        .api_key_repo
-        .init()
-        .await
-        .expect("Failed to initialize database tables");
-    tracing::info!("Database tables initialized");
+    let shutdown = CancellationToken::new();
+    let tasks = state.tasks.clone();
+    shutdown::spawn_signal_listener(shutdown.clone());
 
     if auth_enabled {
         let repo = state.api_key_repo.clone();
-        tokio::spawn(async move {
+        let shutdown = shutdown.clone();
+        tasks.spawn(async move {
             let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(3600)); // 1시간마다
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
                 match repo.cleanup_expired().await {
                     Ok(count) if count > 0 => {
                         tracing::info!("Cleaned up {} expired API keys", count);
@@ -84,35 +137,180 @@ async fn main() {
         });
     }
 
-    let app = if auth_enabled {
-        let protected_routes = Router::new()
-            .route("/{chain}", any(handlers::proxy::proxy_mainnet))
-            .route("/{chain}/{*path}", any(handlers::proxy::proxy_with_path))
-            .layer(middleware::from_fn_with_state(
-                state.clone(),
-                auth_middleware,
-            ));
-
-        Router::new()
-            .route("/health", get(handlers::health::health_check))
-            .route("/chains", get(handlers::chain::list_chains))
-            .route("/auth/register", post(handlers::auth::register))
-            .merge(protected_routes)
-            .layer(TraceLayer::new_for_http())
-            .with_state(state)
+    if let (Some(usage), Some(repo)) = (state.usage.clone(), state.usage_repo.clone()) {
+        let flush_interval = settings.usage.flush_interval_secs.max(1);
+        let shutdown = shutdown.clone();
+        tasks.spawn(async move {
+            let mut interval =
+                tokio::time::interval(tokio::time::Duration::from_secs(flush_interval));
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+                usage.flush(&repo).await;
+            }
+        });
+    }
+
+    if let Some(repo) = state.transactions.clone() {
+        let poll_interval = settings.transactions.poll_interval_secs.max(1);
+        let state = state.clone();
+        let shutdown = shutdown.clone();
+        tasks.spawn(async move {
+            let mut interval =
+                tokio::time::interval(tokio::time::Duration::from_secs(poll_interval));
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+                transactions::poller::poll(&state, &repo).await;
+            }
+        });
+    }
+
+    // 설정 재적용으로 block_pinning 이 추가될 수 있으므로 항상 실행
+    {
+        let state = state.clone();
+        let shutdown = shutdown.clone();
+        tasks.spawn(async move {
+            let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(1));
+            let mut last_polled = HashMap::new();
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => break,
+                    _ = interval.tick() => {}
+                }
+                routing::poll_block_heads(&state, &mut last_polled).await;
+            }
+        });
+    }
+
+    reload::spawn_signal_handler(state.clone(), &tasks, shutdown.clone());
+    if settings.reload.watch {
+        reload::spawn_file_watcher(
+            state.clone(),
+            settings.reload.interval_secs,
+            &tasks,
+            shutdown.clone(),
+        );
+    }
+
+    let mut admin_routes = Router::new();
+    if settings.metrics.enabled {
+        admin_routes = admin_routes.route("/metrics", get(handlers::metrics::metrics_handler));
+    }
+    if settings.admin.token.is_some() {
+        admin_routes = admin_routes.merge(
+            Router::new()
+                .route("/admin/usage", get(handlers::admin::usage_report))
+                .route(
+                    "/admin/transactions",
+                    get(handlers::admin::transaction_report),
+                )
+                .route("/admin/reload", post(handlers::admin::reload_config))
+                .layer(middleware::from_fn_with_state(
+                    state.clone(),
+                    admin_middleware,
+                )),
+        );
+    }
+
+    let proxy_routes = Router::new()
+        .route("/{chain}", any(handlers::proxy::proxy_mainnet))
+        .route("/{chain}/{*path}", any(handlers::proxy::proxy_with_path));
+
+    let proxy_routes = if auth_enabled {
+        proxy_routes.layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth_middleware,
+        ))
     } else {
-        Router::new()
-            .route("/health", get(handlers::health::health_check))
-            .route("/chains", get(handlers::chain::list_chains))
-            .route("/auth/register", post(handlers::auth::register))
-            .route("/{chain}", any(handlers::proxy::proxy_mainnet))
-            .route("/{chain}/{*path}", any(handlers::proxy::proxy_with_path))
-            .layer(TraceLayer::new_for_http())
-            .with_state(state)
+        proxy_routes
     };
 
+    let mut app = Router::new()
+        .route("/health", get(handlers::health::health_check))
+        .route("/chains", get(handlers::chain::list_chains))
+        .route("/auth/register", post(handlers::auth::register))
+        .merge(proxy_routes)
+        .layer(middleware::from_fn_with_state(state.clone(), cors::cors));
+
+    // admin 라우트도 요청 ID 와 접근 로그 레이어를 거치도록 레이어보다 먼저 합침
+    match &settings.admin.bind {
+        Some(admin_addr) => {
+            let listener = tokio::net::TcpListener::bind(admin_addr).await.unwrap();
+            tracing::info!("Admin server running on http://{}", admin_addr);
+            let admin_app = with_request_layers(admin_routes).with_state(state.clone());
+            let shutdown = shutdown.clone();
+            tasks.spawn(async move {
+                axum::serve(listener, admin_app)
+                    .with_graceful_shutdown(shutdown.cancelled_owned())
+                    .await
+                    .unwrap();
+            });
+        }
+        None => app = app.merge(admin_routes),
+    }
+    let app = with_request_layers(app).with_state(state.clone());
+
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
     tracing::info!("Server running on http://{}", addr);
 
-    axum::serve(listener, app).await.unwrap();
+    let mut server = tokio::spawn(
+        axum::serve(listener, app)
+            .with_graceful_shutdown(shutdown.clone().cancelled_owned())
+            .into_future(),
+    );
+
+    // 요청 드레인, 백그라운드 작업 종료, 마지막 사용량 기록이 하나의 마감 시간을 공유
+    let timeout = Duration::from_secs(state.settings().server.shutdown_timeout_secs);
+    let deadline = tokio::select! {
+        result = &mut server => {
+            if let Ok(Err(e)) = result {
+                tracing::error!("Server error: {}", e);
+            }
+            shutdown.cancel();
+            tokio::time::Instant::now() + timeout
+        }
+        _ = shutdown.cancelled() => {
+            let deadline = tokio::time::Instant::now() + timeout;
+            // 새 연결은 더 이상 받지 않고 진행 중인 요청이 끝나기를 기다림
+            tracing::info!("Draining in-flight requests (up to {}s)", timeout.as_secs());
+            if tokio::time::timeout_at(deadline, &mut server).await.is_err() {
+                tracing::warn!("Shutdown deadline exceeded, dropping remaining connections");
+                server.abort();
+            }
+            deadline
+        }
+    };
+
+    tasks.close();
+    match tokio::time::timeout_at(deadline, tasks.wait()).await {
+        Ok(()) => tracing::info!("Background tasks stopped"),
+        Err(_) => tracing::warn!("Background tasks did not stop before the shutdown deadline"),
+    }
+
+    // 드레인 중에 끝난 요청까지 포함해 남은 사용량 기록
+    if let (Some(usage), Some(repo)) = (&state.usage, &state.usage_repo) {
+        if tokio::time::timeout_at(deadline, usage.flush(repo))
+            .await
+            .is_err()
+        {
+            tracing::warn!("Usage flush did not finish before the shutdown deadline");
+        }
+    }
+
+    database.close().await;
+    tracing::info!("Database pool closed");
+
+    tracing_guard.shutdown();
+}
+
+fn with_request_layers(router: Router<AppState>) -> Router<AppState> {
+    router
+        .layer(TraceLayer::new_for_http().make_span_with(telemetry::trace::make_span))
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
 }
@@ -1,11 +1,74 @@
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Map, Value};
 
-#[derive(Debug, Deserialize, Serialize)]
+// jsonrpc 는 생략 가능 (bitcoind 클라이언트는 "1.0" 또는 생략), id 는 받은 값을 그대로 응답에 사용.
+// 이름 기반 params ({ .. }) 는 named_params 에 두고 업스트림에는 그대로 전달
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "RawRpcRequest", into = "RawRpcRequest")]
 pub struct RpcRequest {
     pub jsonrpc: String,
     pub method: String,
-    #[serde(default)]
     pub params: Vec<Value>,
-    pub id: u64,
+    pub named_params: Option<Map<String, Value>>,
+    pub id: Value,
+}
+
+impl RpcRequest {
+    // 위치 기반이면 index 번째, 이름 기반이면 name 인 인자
+    pub fn param(&self, index: usize, name: &str) -> Option<&Value> {
+        match &self.named_params {
+            Some(named) => named.get(name),
+            None => self.params.get(index),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct RawRpcRequest {
+    #[serde(default = "default_jsonrpc")]
+    jsonrpc: String,
+    method: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    params: Value,
+    #[serde(default)]
+    id: Value,
+}
+
+fn default_jsonrpc() -> String {
+    "2.0".to_string()
+}
+
+impl TryFrom<RawRpcRequest> for RpcRequest {
+    type Error = String;
+
+    fn try_from(raw: RawRpcRequest) -> Result<Self, Self::Error> {
+        let (params, named_params) = match raw.params {
+            Value::Null => (Vec::new(), None),
+            Value::Array(params) => (params, None),
+            Value::Object(named) => (Vec::new(), Some(named)),
+            _ => return Err("params must be an array or an object".to_string()),
+        };
+        Ok(Self {
+            jsonrpc: raw.jsonrpc,
+            method: raw.method,
+            params,
+            named_params,
+            id: raw.id,
+        })
+    }
+}
+
+impl From<RpcRequest> for RawRpcRequest {
+    fn from(request: RpcRequest) -> Self {
+        let params = match request.named_params {
+            Some(named) => Value::Object(named),
+            None => Value::Array(request.params),
+        };
+        Self {
+            jsonrpc: request.jsonrpc,
+            method: request.method,
+            params,
+            id: request.id,
+        }
+    }
 }
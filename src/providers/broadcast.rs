@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+use tokio_util::task::TaskTracker;
+use tracing::Instrument;
+
+use super::jsonrpc;
+use crate::{
+    chains::evm,
+    config::CredentialConfig,
+    error::AppError,
+    models::rpc::RpcRequest,
+    routing::{HealthTracker, Upstream},
+};
+
+// 다른 노드를 통해 이미 멤풀에 들어간 트랜잭션에 대한 노드별 에러 메시지
+const ALREADY_KNOWN: &[&str] = &[
+    "already known",
+    "known transaction",
+    "already imported",
+    "already exists",
+];
+const NONCE_TOO_LOW: &str = "nonce too low";
+const GET_TRANSACTION_BY_HASH: &str = "eth_getTransactionByHash";
+
+struct Endpoint {
+    name: String,
+    url: String,
+    credential: Option<CredentialConfig>,
+    primary: bool,
+}
+
+enum Outcome {
+    Accepted(String),
+    Rejected(Value),
+    Failed(AppError),
+}
+
+// 모든 정상 엔드포인트에 병렬로 전송하고 처음 성공한 해시를 반환.
+// 나머지 전송은 응답을 반환한 뒤에도 백그라운드에서 계속 진행되며 종료 시 완료를 기다림
+pub async fn broadcast(
+    client: &Client,
+    health: &HealthTracker,
+    tasks: &TaskTracker,
+    targets: Vec<Upstream<'_>>,
+    request: &RpcRequest,
+) -> Result<Response, AppError> {
+    let tx_hash = request
+        .params
+        .first()
+        .and_then(Value::as_str)
+        .and_then(evm::transaction_hash);
+
+    let mut endpoints: Vec<Endpoint> = targets
+        .iter()
+        .enumerate()
+        .map(|(index, target)| Endpoint {
+            name: target.name.to_string(),
+            url: target.urls[0].to_string(),
+            credential: target.credential.cloned(),
+            primary: index == 0,
+        })
+        .collect();
+    if endpoints
+        .iter()
+        .any(|endpoint| health.is_healthy(&endpoint.url))
+    {
+        endpoints.retain(|endpoint| health.is_healthy(&endpoint.url));
+    }
+
+    let request = Arc::new(request.clone());
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+
+    for endpoint in endpoints {
+        let span = tracing::info_span!(
+            "upstream.broadcast",
+            otel.kind = "client",
+            upstream = %endpoint.name,
+            primary = endpoint.primary,
+        );
+        let (client, health, request, tx_hash, sender) = (
+            client.clone(),
+            health.clone(),
+            request.clone(),
+            tx_hash.clone(),
+            sender.clone(),
+        );
+
+        tasks.spawn(
+            async move {
+                let outcome = send(&client, &endpoint, &request, tx_hash.as_deref()).await;
+                match &outcome {
+                    Outcome::Failed(e) => {
+                        tracing::warn!(upstream = %endpoint.name, error = %e, "Broadcast endpoint failed");
+                        health.record_failure(&endpoint.url);
+                    }
+                    _ => health.record_success(&endpoint.url),
+                }
+                let _ = sender.send((endpoint.primary, outcome));
+            }
+            .instrument(span),
+        );
+    }
+    drop(sender);
+
+    let mut rejection = None;
+    let mut last_error = AppError::ProviderError("No broadcast endpoints".to_string());
+
+    while let Some((primary, outcome)) = receiver.recv().await {
+        match outcome {
+            Outcome::Accepted(hash) => {
+                return Ok(Json(json!({
+                    "jsonrpc": "2.0",
+                    "id": request.id,
+                    "result": hash
+                }))
+                .into_response());
+            }
+            // 모두 실패하면 기본 업스트림의 에러 응답을 우선 반환
+            Outcome::Rejected(body) => {
+                if primary || rejection.is_none() {
+                    rejection = Some(body);
+                }
+            }
+            Outcome::Failed(e) => last_error = e,
+        }
+    }
+
+    match rejection {
+        Some(body) => Ok(Json(body).into_response()),
+        None => Err(last_error),
+    }
+}
+
+async fn send(
+    client: &Client,
+    endpoint: &Endpoint,
+    request: &RpcRequest,
+    tx_hash: Option<&str>,
+) -> Outcome {
+    let response =
+        match jsonrpc::call(client, &endpoint.url, endpoint.credential.as_ref(), request).await {
+            Ok(response) => response,
+            Err(e) => return Outcome::Failed(e),
+        };
+
+    if let Some(hash) = response.get("result").and_then(Value::as_str) {
+        return Outcome::Accepted(hash.to_string());
+    }
+
+    let message = response
+        .pointer("/error/message")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_lowercase();
+
+    if let (false, Some(hash)) = (endpoint.primary, tx_hash) {
+        if ALREADY_KNOWN.iter().any(|known| message.contains(known)) {
+            return Outcome::Accepted(hash.to_string());
+        }
+        if message.contains(NONCE_TOO_LOW) && is_included(client, endpoint, &request.id, hash).await
+        {
+            return Outcome::Accepted(hash.to_string());
+        }
+    }
+
+    Outcome::Rejected(response)
+}
+
+// nonce too low 는 같은 트랜잭션이 이미 포함된 경우에도 반환되므로 해시로 조회해 확인
+async fn is_included(client: &Client, endpoint: &Endpoint, id: &Value, hash: &str) -> bool {
+    let lookup = RpcRequest {
+        jsonrpc: "2.0".to_string(),
+        method: GET_TRANSACTION_BY_HASH.to_string(),
+        params: vec![json!(hash)],
+        named_params: None,
+        id: id.clone(),
+    };
+
+    jsonrpc::call(client, &endpoint.url, endpoint.credential.as_ref(), &lookup)
+        .await
+        .ok()
+        .and_then(|response| response.get("result").cloned())
+        .is_some_and(|result| !result.is_null())
+}
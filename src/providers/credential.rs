@@ -0,0 +1,74 @@
+use reqwest::RequestBuilder;
+
+use crate::config::{redact_url, CredentialConfig, CredentialPlacement};
+
+const DEFAULT_QUERY_PARAM: &str = "apikey";
+const DEFAULT_HEADER: &str = "x-api-key";
+const PATH_PLACEHOLDER: &str = "{api_key}";
+
+pub fn apply_to_url(url: &str, credential: Option<&CredentialConfig>) -> String {
+    let Some((credential, key)) = resolved(credential) else {
+        return url.replace(PATH_PLACEHOLDER, "");
+    };
+
+    if credential.placement != CredentialPlacement::Path {
+        return url.to_string();
+    }
+
+    if url.contains(PATH_PLACEHOLDER) {
+        url.replace(PATH_PLACEHOLDER, key)
+    } else {
+        format!("{}/{}", url.trim_end_matches('/'), key)
+    }
+}
+
+pub fn apply_to_request(
+    request: RequestBuilder,
+    credential: Option<&CredentialConfig>,
+) -> RequestBuilder {
+    let Some((credential, key)) = resolved(credential) else {
+        return request;
+    };
+
+    match credential.placement {
+        CredentialPlacement::Query => {
+            let name = credential.name.as_deref().unwrap_or(DEFAULT_QUERY_PARAM);
+            request.query(&[(name, key)])
+        }
+        CredentialPlacement::Path => request,
+        CredentialPlacement::Header => {
+            let name = credential.name.as_deref().unwrap_or(DEFAULT_HEADER);
+            request.header(name, key)
+        }
+        CredentialPlacement::Bearer => request.bearer_auth(key),
+        CredentialPlacement::Basic => match &credential.username {
+            Some(username) => request.basic_auth(username, Some(key)),
+            None => request.basic_auth(key, None::<&str>),
+        },
+    }
+}
+
+pub fn query_param_name(credential: Option<&CredentialConfig>) -> Option<&str> {
+    let (credential, _) = resolved(credential)?;
+    (credential.placement == CredentialPlacement::Query)
+        .then(|| credential.name.as_deref().unwrap_or(DEFAULT_QUERY_PARAM))
+}
+
+fn resolved(credential: Option<&CredentialConfig>) -> Option<(&CredentialConfig, &str)> {
+    let credential = credential?;
+    let key = credential.key.as_deref().filter(|k| !k.is_empty())?;
+    Some((credential, key))
+}
+
+// reqwest 에러 메시지 끝의 " for url (...)" 에는 주입된 키가 들어 있으므로 URL 을 떼어 내고,
+// 로그에는 가린 URL 만 남김
+pub fn upstream_error(e: reqwest::Error) -> String {
+    let url = e.url().map(|url| redact_url(url.as_str()));
+    let message = e.without_url().to_string();
+    tracing::debug!(
+        url = url.as_deref().unwrap_or("-"),
+        error = %message,
+        "Upstream request failed"
+    );
+    message
+}
@@ -0,0 +1,380 @@
+use axum::{
+    http::{Method, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use reqwest::Client;
+use serde_json::{json, Value};
+use tracing::Instrument;
+
+use super::{credential, jsonrpc, rest};
+use crate::{
+    chains::bitcoin, config::NetworkConfig, error::AppError, models::rpc::RpcRequest,
+    telemetry::trace,
+};
+
+pub const GET_BLOCK_COUNT: &str = "getblockcount";
+pub const GET_BEST_BLOCK_HASH: &str = "getbestblockhash";
+pub const GET_BLOCK_HASH: &str = "getblockhash";
+pub const GET_RAW_TRANSACTION: &str = "getrawtransaction";
+pub const ESTIMATE_SMART_FEE: &str = "estimatesmartfee";
+
+const SATS_PER_BTC: f64 = 100_000_000.0;
+
+// bitcoind 에러 코드
+const RPC_METHOD_NOT_FOUND: i64 = -32601;
+const RPC_MISC_ERROR: i64 = -1;
+const RPC_INVALID_ADDRESS_OR_KEY: i64 = -5;
+const RPC_INVALID_PARAMETER: i64 = -8;
+const RPC_VERIFY_ERROR: i64 = -25;
+
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+// bitcoind JSON-RPC 요청을 Esplora REST 호출로 바꿔 처리하고 bitcoind 응답 형식으로 반환.
+// 업스트림 연결 실패나 5xx 는 AppError, 그 외 실패는 bitcoind 스타일 error 객체로 응답
+pub async fn call(
+    client: &Client,
+    network: &NetworkConfig,
+    request: &RpcRequest,
+) -> Result<Response, AppError> {
+    let esplora = Esplora { client, network };
+    let param = |index, name| request.param(index, name);
+
+    let result = match request.method.as_str() {
+        GET_BLOCK_COUNT => esplora.tip_height().await?.map(|height| json!(height)),
+        GET_BEST_BLOCK_HASH => esplora.text("blocks/tip/hash").await?.map(Value::String),
+        GET_BLOCK_HASH => match param(0, "height").and_then(Value::as_u64) {
+            Some(height) => esplora
+                .text(&format!("block-height/{}", height))
+                .await?
+                .map(Value::String)
+                .map_err(|_| RpcError::new(RPC_INVALID_PARAMETER, "Block height out of range")),
+            None => Err(missing("height")),
+        },
+        GET_RAW_TRANSACTION => match param(0, "txid").and_then(Value::as_str) {
+            Some(txid) if verbose(param(1, "verbose").or_else(|| param(1, "verbosity"))) => {
+                esplora.verbose_transaction(txid).await?
+            }
+            Some(txid) => esplora
+                .text(&format!("tx/{}/hex", txid))
+                .await?
+                .map(Value::String)
+                .map_err(|_| not_found()),
+            None => Err(missing("txid")),
+        },
+        bitcoin::SEND_RAW_TRANSACTION => match param(0, "hexstring").and_then(Value::as_str) {
+            Some(hex) => esplora.send(hex).await?,
+            None => Err(missing("hexstring")),
+        },
+        ESTIMATE_SMART_FEE => match param(0, "conf_target").and_then(Value::as_u64) {
+            Some(target) => esplora.estimate_fee(target).await?,
+            None => Err(missing("conf_target")),
+        },
+        method => Err(RpcError::new(
+            RPC_METHOD_NOT_FOUND,
+            format!("Method not found: {}", method),
+        )),
+    };
+
+    let method_not_found = matches!(&result, Err(error) if error.code == RPC_METHOD_NOT_FOUND);
+    let body = match result {
+        Ok(result) => json!({ "result": result, "error": null, "id": request.id }),
+        Err(error) => json!({
+            "result": null,
+            "error": { "code": error.code, "message": error.message },
+            "id": request.id
+        }),
+    };
+    let mut response = Json(body).into_response();
+    if method_not_found {
+        response.extensions_mut().insert(jsonrpc::MethodNotFound);
+    }
+    Ok(response)
+}
+
+struct Esplora<'a> {
+    client: &'a Client,
+    network: &'a NetworkConfig,
+}
+
+impl Esplora<'_> {
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<(StatusCode, String), AppError> {
+        let url = rest::build_url(self.network, path, None)?;
+        let span = tracing::info_span!(
+            "upstream.rest",
+            otel.kind = "client",
+            http.method = %method,
+            server.address = url.host_str().unwrap_or_default(),
+        );
+
+        let mut request = credential::apply_to_request(
+            self.client.request(method, url),
+            self.network.credential.as_ref(),
+        );
+        request = span.in_scope(|| trace::inject_context(request));
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+
+        let response = request
+            .send()
+            .instrument(span)
+            .await
+            .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))?;
+        let status = StatusCode::from_u16(response.status().as_u16())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let text = response
+            .text()
+            .await
+            .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))?;
+
+        if status.is_server_error() {
+            return Err(AppError::ProviderError(format!(
+                "Esplora returned {}: {}",
+                status,
+                text.trim()
+            )));
+        }
+        Ok((status, text.trim().to_string()))
+    }
+
+    // 성공이면 본문, 4xx 면 에러 메시지
+    async fn text(&self, path: &str) -> Result<Result<String, RpcError>, AppError> {
+        let (status, text) = self.request(Method::GET, path, None).await?;
+        if status.is_success() {
+            Ok(Ok(text))
+        } else {
+            Ok(Err(RpcError::new(RPC_MISC_ERROR, text)))
+        }
+    }
+
+    async fn json(&self, path: &str) -> Result<Result<Value, RpcError>, AppError> {
+        Ok(match self.text(path).await? {
+            Ok(text) => Ok(serde_json::from_str(&text).map_err(|e| {
+                AppError::ProviderError(format!("Invalid Esplora response: {}", e))
+            })?),
+            Err(error) => Err(error),
+        })
+    }
+
+    async fn tip_height(&self) -> Result<Result<u64, RpcError>, AppError> {
+        Ok(match self.text("blocks/tip/height").await? {
+            Ok(text) => Ok(text
+                .parse()
+                .map_err(|_| AppError::ProviderError("Invalid Esplora tip height".to_string()))?),
+            Err(error) => Err(error),
+        })
+    }
+
+    async fn verbose_transaction(&self, txid: &str) -> Result<Result<Value, RpcError>, AppError> {
+        let (tx_path, hex_path) = (format!("tx/{}", txid), format!("tx/{}/hex", txid));
+        let (tx, hex, tip) =
+            tokio::try_join!(self.json(&tx_path), self.text(&hex_path), self.tip_height())?;
+        let (Ok(tx), Ok(hex)) = (tx, hex) else {
+            return Ok(Err(not_found()));
+        };
+        Ok(Ok(raw_transaction(&tx, hex, tip.ok())))
+    }
+
+    async fn send(&self, hex: &str) -> Result<Result<Value, RpcError>, AppError> {
+        let (status, text) = self
+            .request(Method::POST, bitcoin::BROADCAST_PATH, Some(hex.to_string()))
+            .await?;
+        if status.is_success() {
+            return Ok(Ok(Value::String(text)));
+        }
+
+        // mempool.space 는 bitcoind 의 에러를 "sendrawtransaction RPC error: {...}" 형태로 전달
+        let error = text
+            .find('{')
+            .and_then(|start| serde_json::from_str::<Value>(&text[start..]).ok())
+            .and_then(|error| {
+                let code = error.get("code")?.as_i64()?;
+                let message = error.get("message")?.as_str()?.to_string();
+                Some(RpcError::new(code, message))
+            })
+            .unwrap_or_else(|| RpcError::new(RPC_VERIFY_ERROR, text));
+        Ok(Err(error))
+    }
+
+    // Esplora 는 확인 블록 수별 sat/vB, bitcoind 는 BTC/kvB
+    async fn estimate_fee(&self, target: u64) -> Result<Result<Value, RpcError>, AppError> {
+        let estimates = match self.json("fee-estimates").await? {
+            Ok(estimates) => estimates,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        let mut estimates: Vec<(u64, f64)> = estimates
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(blocks, rate)| Some((blocks.parse().ok()?, rate.as_f64()?)))
+            .collect();
+        estimates.sort_by_key(|(blocks, _)| *blocks);
+
+        let estimate = estimates
+            .iter()
+            .find(|(blocks, _)| *blocks >= target)
+            .or(estimates.last());
+        Ok(Ok(match estimate {
+            Some((blocks, sat_per_vb)) => json!({
+                "feerate": round_btc(sat_per_vb * 1000.0 / SATS_PER_BTC),
+                "blocks": blocks
+            }),
+            None => json!({
+                "errors": ["Insufficient data or no feerate found"],
+                "blocks": target
+            }),
+        }))
+    }
+}
+
+// Esplora 트랜잭션을 getrawtransaction verbose 형식으로 변환. wtxid 는 제공되지 않아 hash 에 txid 를 사용
+fn raw_transaction(tx: &Value, hex: String, tip: Option<u64>) -> Value {
+    let weight = tx.get("weight").and_then(Value::as_u64).unwrap_or_default();
+
+    let vin: Vec<Value> = tx
+        .get("vin")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|input| {
+            let mut vin = if input.get("is_coinbase").and_then(Value::as_bool) == Some(true) {
+                json!({ "coinbase": input.get("scriptsig") })
+            } else {
+                json!({
+                    "txid": input.get("txid"),
+                    "vout": input.get("vout"),
+                    "scriptSig": {
+                        "asm": input.get("scriptsig_asm"),
+                        "hex": input.get("scriptsig")
+                    }
+                })
+            };
+            if let Some(witness) = input.get("witness").filter(|w| !w.is_null()) {
+                vin["txinwitness"] = witness.clone();
+            }
+            vin["sequence"] = input.get("sequence").cloned().unwrap_or(Value::Null);
+            vin
+        })
+        .collect();
+
+    let vout: Vec<Value> = tx
+        .get("vout")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(n, output)| {
+            let sats = output
+                .get("value")
+                .and_then(Value::as_u64)
+                .unwrap_or_default();
+            let kind = output
+                .get("scriptpubkey_type")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let mut script = json!({
+                "asm": output.get("scriptpubkey_asm"),
+                "hex": output.get("scriptpubkey"),
+                "type": script_type(kind)
+            });
+            if let Some(address) = output.get("scriptpubkey_address") {
+                script["address"] = address.clone();
+            }
+            json!({
+                "value": round_btc(sats as f64 / SATS_PER_BTC),
+                "n": n,
+                "scriptPubKey": script
+            })
+        })
+        .collect();
+
+    let mut raw = json!({
+        "txid": tx.get("txid"),
+        "hash": tx.get("txid"),
+        "version": tx.get("version"),
+        "size": tx.get("size"),
+        "vsize": weight.div_ceil(4),
+        "weight": weight,
+        "locktime": tx.get("locktime"),
+        "vin": vin,
+        "vout": vout,
+        "hex": hex
+    });
+
+    let status = tx.get("status");
+    if status
+        .and_then(|s| s.get("confirmed"))
+        .and_then(Value::as_bool)
+        == Some(true)
+    {
+        let status = status.unwrap_or(&Value::Null);
+        let height = status.get("block_height").and_then(Value::as_u64);
+        raw["blockhash"] = status.get("block_hash").cloned().unwrap_or(Value::Null);
+        if let (Some(tip), Some(height)) = (tip, height) {
+            raw["confirmations"] = json!(tip.saturating_sub(height) + 1);
+        }
+        raw["time"] = status.get("block_time").cloned().unwrap_or(Value::Null);
+        raw["blocktime"] = raw["time"].clone();
+    }
+    raw
+}
+
+fn script_type(esplora: &str) -> &'static str {
+    match esplora {
+        "p2pk" => "pubkey",
+        "p2pkh" => "pubkeyhash",
+        "p2sh" => "scripthash",
+        "v0_p2wpkh" => "witness_v0_keyhash",
+        "v0_p2wsh" => "witness_v0_scripthash",
+        "v1_p2tr" => "witness_v1_taproot",
+        "op_return" => "nulldata",
+        "multisig" => "multisig",
+        _ => "nonstandard",
+    }
+}
+
+// verbose 는 bool 또는 verbosity 숫자 (0 이면 hex)
+fn verbose(param: Option<&Value>) -> bool {
+    match param {
+        Some(Value::Bool(verbose)) => *verbose,
+        Some(Value::Number(verbosity)) => verbosity.as_u64().is_some_and(|v| v > 0),
+        _ => false,
+    }
+}
+
+fn round_btc(value: f64) -> f64 {
+    (value * SATS_PER_BTC).round() / SATS_PER_BTC
+}
+
+fn missing(name: &str) -> RpcError {
+    RpcError::new(
+        RPC_INVALID_PARAMETER,
+        format!("Missing required parameter: {}", name),
+    )
+}
+
+fn not_found() -> RpcError {
+    RpcError::new(
+        RPC_INVALID_ADDRESS_OR_KEY,
+        "No such mempool or blockchain transaction",
+    )
+}
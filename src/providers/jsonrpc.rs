@@ -4,20 +4,73 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use reqwest::Client;
+use serde_json::Value;
+use tracing::Instrument;
 
-use crate::{error::AppError, models::rpc::RpcRequest};
+use super::credential;
+use crate::{
+    config::CredentialConfig,
+    error::AppError,
+    models::rpc::RpcRequest,
+    routing::{HealthTracker, Upstream},
+    telemetry::trace,
+};
+
+// 업스트림이 method not found (-32601) 로 응답했음을 응답 extension 으로 표시
+#[derive(Debug, Clone, Copy)]
+pub struct MethodNotFound;
+
+const METHOD_NOT_FOUND: i64 = -32601;
+// 에러 응답은 작으므로 이 크기 이하의 본문만 해석
+const MAX_ERROR_BODY: usize = 4096;
+
+pub async fn forward_upstream(
+    client: &Client,
+    health: &HealthTracker,
+    upstream: &Upstream<'_>,
+    request: &RpcRequest,
+) -> Result<Response, AppError> {
+    let mut last_error =
+        AppError::ProviderError(format!("Upstream '{}' has no endpoints", upstream.name));
+
+    for url in health.order(&upstream.urls) {
+        let span = tracing::info_span!(
+            "upstream.jsonrpc",
+            otel.kind = "client",
+            upstream = %upstream.name,
+            rpc.method = %request.method,
+        );
+        match forward(client, url, upstream.credential, request)
+            .instrument(span)
+            .await
+        {
+            Err(AppError::ProviderError(e)) => {
+                tracing::warn!(upstream = %upstream.name, error = %e, "Upstream endpoint failed");
+                health.record_failure(url);
+                last_error = AppError::ProviderError(e);
+            }
+            result => {
+                match &result {
+                    Ok(response) if !response.status().is_server_error() => {
+                        health.record_success(url)
+                    }
+                    _ => health.record_failure(url),
+                }
+                return result;
+            }
+        }
+    }
+
+    Err(last_error)
+}
 
 pub async fn forward(
     client: &Client,
     url: &str,
+    credential: Option<&CredentialConfig>,
     request: &RpcRequest,
 ) -> Result<Response, AppError> {
-    let response = client
-        .post(url)
-        .json(request)
-        .send()
-        .await
-        .map_err(|e| AppError::ProviderError(e.to_string()))?;
+    let response = send(client, url, credential, request).await?;
 
     let status = StatusCode::from_u16(response.status().as_u16())
         .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
@@ -25,13 +78,63 @@ pub async fn forward(
     let body = response
         .bytes()
         .await
-        .map_err(|e| AppError::ParseError(e.to_string()))?;
+        .map_err(|e| AppError::ParseError(credential::upstream_error(e)))?;
 
     tracing::info!("JSON-RPC request successful");
 
-    Ok(Response::builder()
+    let method_not_found = body.len() <= MAX_ERROR_BODY && is_method_not_found(&body);
+    let mut response = Response::builder()
         .status(status)
         .header("content-type", "application/json")
         .body(Body::from(body))
-        .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response()))
+        .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response());
+    if method_not_found {
+        response.extensions_mut().insert(MethodNotFound);
+    }
+    Ok(response)
+}
+
+fn is_method_not_found(body: &[u8]) -> bool {
+    serde_json::from_slice::<Value>(body)
+        .ok()
+        .and_then(|value| value.pointer("/error/code").and_then(Value::as_i64))
+        == Some(METHOD_NOT_FOUND)
+}
+
+// 응답 본문을 그대로 돌려주지 않고 JSON 으로 해석해야 하는 경우 (브로드캐스트 등)
+pub async fn call(
+    client: &Client,
+    url: &str,
+    credential: Option<&CredentialConfig>,
+    request: &RpcRequest,
+) -> Result<Value, AppError> {
+    let response = send(client, url, credential, request).await?;
+
+    if response.status().is_server_error() {
+        return Err(AppError::ProviderError(format!(
+            "Upstream returned {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))
+}
+
+async fn send(
+    client: &Client,
+    url: &str,
+    credential: Option<&CredentialConfig>,
+    request: &RpcRequest,
+) -> Result<reqwest::Response, AppError> {
+    let url = credential::apply_to_url(url, credential);
+
+    let request_builder = credential::apply_to_request(client.post(&url), credential);
+    trace::inject_context(request_builder)
+        .json(request)
+        .send()
+        .await
+        .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))
 }
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::{sync::Semaphore, task::JoinSet};
+
+use super::jsonrpc;
+use crate::{
+    chains::evm,
+    config::{CredentialConfig, GetLogsConfig},
+    error::AppError,
+    models::rpc::RpcRequest,
+    routing::{HealthTracker, Upstream},
+};
+
+// fromBlock..=toBlock 를 max_range 단위로 나눠 최대 concurrency 개씩 병렬 조회한 뒤
+// (blockNumber, logIndex) 순으로 합쳐 하나의 응답으로 반환. 한 구간이라도 에러면 그 에러를 반환
+pub async fn split(
+    client: &Client,
+    health: &HealthTracker,
+    upstream: &Upstream<'_>,
+    request: &RpcRequest,
+    (from, to): (u64, u64),
+    config: &GetLogsConfig,
+) -> Result<Response, AppError> {
+    let urls: Arc<Vec<String>> = Arc::new(
+        health
+            .order(&upstream.urls)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+    );
+    let credential = upstream.credential.cloned();
+    let permits = Arc::new(Semaphore::new(config.concurrency));
+
+    let mut chunks = JoinSet::new();
+    let mut start = from;
+    let mut index = 0;
+    while start <= to {
+        let end = start.saturating_add(config.max_range - 1).min(to);
+        let chunk = chunk_request(request, start, end);
+        let (client, health, urls) = (client.clone(), health.clone(), urls.clone());
+        let (credential, permits) = (credential.clone(), permits.clone());
+
+        chunks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            let response = call(&client, &health, &urls, credential.as_ref(), &chunk).await;
+            (index, response)
+        });
+
+        index += 1;
+        match end.checked_add(1) {
+            Some(next) => start = next,
+            None => break,
+        }
+    }
+
+    tracing::debug!(from, to, chunks = index, "Split eth_getLogs range");
+
+    let mut results = vec![Vec::new(); index];
+    while let Some(joined) = chunks.join_next().await {
+        let (index, response) = joined
+            .map_err(|e| AppError::ProviderError(format!("eth_getLogs chunk failed: {}", e)))?;
+        let mut response = match response {
+            Ok(response) => response,
+            Err(err) => {
+                chunks.abort_all();
+                return Err(err);
+            }
+        };
+
+        if let Some(error) = response.get_mut("error").filter(|error| !error.is_null()) {
+            chunks.abort_all();
+            let error = error.take();
+            return Ok(
+                Json(json!({ "jsonrpc": "2.0", "id": request.id, "error": error })).into_response(),
+            );
+        }
+
+        match response.get_mut("result").map(Value::take) {
+            Some(Value::Array(logs)) => results[index] = logs,
+            _ => {
+                chunks.abort_all();
+                return Err(AppError::ProviderError(
+                    "Invalid eth_getLogs response".to_string(),
+                ));
+            }
+        }
+    }
+
+    let mut logs: Vec<Value> = results.into_iter().flatten().collect();
+    logs.sort_by_key(|log| (quantity(log, "blockNumber"), quantity(log, "logIndex")));
+
+    Ok(Json(json!({ "jsonrpc": "2.0", "id": request.id, "result": logs })).into_response())
+}
+
+pub async fn block_number(
+    client: &Client,
+    health: &HealthTracker,
+    upstream: &Upstream<'_>,
+) -> Result<u64, AppError> {
+    let urls: Vec<String> = health
+        .order(&upstream.urls)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let request = RpcRequest {
+        jsonrpc: "2.0".to_string(),
+        method: evm::BLOCK_NUMBER.to_string(),
+        params: Vec::new(),
+        named_params: None,
+        id: json!(1),
+    };
+
+    let response = call(client, health, &urls, upstream.credential, &request).await?;
+    response
+        .get("result")
+        .and_then(Value::as_str)
+        .and_then(evm::parse_quantity)
+        .ok_or_else(|| AppError::ProviderError("Invalid eth_blockNumber response".to_string()))
+}
+
+// "safe" / "finalized" 태그가 가리키는 블록 번호
+pub async fn tagged_block_number(
+    client: &Client,
+    health: &HealthTracker,
+    upstream: &Upstream<'_>,
+    tag: &str,
+) -> Result<u64, AppError> {
+    let urls: Vec<String> = health
+        .order(&upstream.urls)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let request = RpcRequest {
+        jsonrpc: "2.0".to_string(),
+        method: "eth_getBlockByNumber".to_string(),
+        params: vec![json!(tag), json!(false)],
+        named_params: None,
+        id: json!(1),
+    };
+
+    let response = call(client, health, &urls, upstream.credential, &request).await?;
+    response
+        .pointer("/result/number")
+        .and_then(Value::as_str)
+        .and_then(evm::parse_quantity)
+        .ok_or_else(|| {
+            AppError::ProviderError(format!("Invalid eth_getBlockByNumber({}) response", tag))
+        })
+}
+
+fn chunk_request(request: &RpcRequest, from: u64, to: u64) -> RpcRequest {
+    let mut chunk = request.clone();
+    if let Some(filter) = chunk.params.first_mut().and_then(Value::as_object_mut) {
+        filter.insert("fromBlock".to_string(), json!(format!("0x{:x}", from)));
+        filter.insert("toBlock".to_string(), json!(format!("0x{:x}", to)));
+    }
+    chunk
+}
+
+async fn call(
+    client: &Client,
+    health: &HealthTracker,
+    urls: &[String],
+    credential: Option<&CredentialConfig>,
+    request: &RpcRequest,
+) -> Result<Value, AppError> {
+    let mut last_error = AppError::ProviderError("Upstream has no endpoints".to_string());
+
+    for url in urls {
+        match jsonrpc::call(client, url, credential, request).await {
+            Ok(response) => {
+                health.record_success(url);
+                return Ok(response);
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "Upstream endpoint failed");
+                health.record_failure(url);
+                last_error = err;
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+fn quantity(log: &Value, key: &str) -> u64 {
+    log.get(key)
+        .and_then(Value::as_str)
+        .and_then(evm::parse_quantity)
+        .unwrap_or_default()
+}
@@ -1,2 +1,6 @@
+pub mod broadcast;
+pub mod credential;
+pub mod esplora;
 pub mod jsonrpc;
+pub mod logs;
 pub mod rest;
@@ -3,46 +3,48 @@ use axum::{
     http::{Method, StatusCode},
     response::{IntoResponse, Response},
 };
+use percent_encoding::percent_decode_str;
 use reqwest::Client;
+use tracing::Instrument;
+use url::Url;
 
-use crate::error::AppError;
+use super::credential;
+use crate::{
+    config::{CredentialConfig, NetworkConfig},
+    error::AppError,
+    telemetry::trace,
+};
 
 pub async fn forward(
     client: &Client,
-    base_url: &str,
     method: Method,
-    path: &str,
-    query: Option<&str>,
-    api_key: Option<&str>,
-    body: Option<Body>,
+    url: Url,
+    credential: Option<&CredentialConfig>,
+    body: Body,
 ) -> Result<Response, AppError> {
-    let url = build_url(base_url, path, query, api_key);
-
-    let mut request = match method {
-        Method::GET => client.get(&url),
-        Method::POST => client.post(&url),
-        Method::PUT => client.put(&url),
-        Method::DELETE => client.delete(&url),
-        Method::PATCH => client.patch(&url),
-        _ => {
-            return Err(AppError::ProtocolMismatch(format!(
-                "Unsupported method: {}",
-                method
-            )))
-        }
-    };
+    let span = tracing::info_span!(
+        "upstream.rest",
+        otel.kind = "client",
+        http.method = %method,
+        server.address = url.host_str().unwrap_or_default(),
+    );
+
+    let is_head = method == Method::HEAD;
+    let mut request = credential::apply_to_request(client.request(method, url), credential);
+    request = span.in_scope(|| trace::inject_context(request));
 
-    if let Some(body) = body {
-        let bytes = axum::body::to_bytes(body, usize::MAX)
-            .await
-            .map_err(|e| AppError::ParseError(e.to_string()))?;
+    let bytes = axum::body::to_bytes(body, usize::MAX)
+        .await
+        .map_err(|e| AppError::ParseError(e.to_string()))?;
+    if !bytes.is_empty() {
         request = request.body(bytes);
     }
 
     let response = request
         .send()
+        .instrument(span)
         .await
-        .map_err(|e| AppError::ProviderError(e.to_string()))?;
+        .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))?;
 
     let status = StatusCode::from_u16(response.status().as_u16())
         .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
@@ -51,12 +53,20 @@ pub async fn forward(
     let body = response
         .bytes()
         .await
-        .map_err(|e| AppError::ParseError(e.to_string()))?;
+        .map_err(|e| AppError::ParseError(credential::upstream_error(e)))?;
 
     let mut builder = Response::builder().status(status);
 
-    if let Some(content_type) = headers.get("content-type") {
-        builder = builder.header("content-type", content_type);
+    for name in ["content-type", "allow"] {
+        if let Some(value) = headers.get(name) {
+            builder = builder.header(name, value);
+        }
+    }
+
+    if is_head {
+        if let Some(length) = headers.get("content-length") {
+            builder = builder.header("content-length", length);
+        }
     }
 
     Ok(builder
@@ -64,17 +74,135 @@ pub async fn forward(
         .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response()))
 }
 
-fn build_url(base_url: &str, path: &str, query: Option<&str>, api_key: Option<&str>) -> String {
-    let base = if path.is_empty() {
-        base_url.to_string()
-    } else {
-        format!("{}/{}", base_url, path)
-    };
-
-    match (query, api_key) {
-        (Some(q), Some(key)) => format!("{}?{}&apikey={}", base, q, key),
-        (Some(q), None) => format!("{}?{}", base, q),
-        (None, Some(key)) => format!("{}?apikey={}", base, key),
-        (None, None) => base,
+pub fn build_url(
+    network: &NetworkConfig,
+    path: &str,
+    query: Option<&str>,
+) -> Result<Url, AppError> {
+    let segments = normalize_path(path)?;
+
+    if !network.allowed_paths.is_empty()
+        && !network
+            .allowed_paths
+            .iter()
+            .any(|prefix| has_prefix(&segments, prefix))
+    {
+        return Err(AppError::PathNotAllowed(format!("/{}", segments.join("/"))));
+    }
+
+    join_url(
+        network.rest_url.as_deref().unwrap_or_default(),
+        network.credential.as_ref(),
+        &segments,
+        query,
+    )
+}
+
+// JSON-RPC 업스트림에 보내는 URI 형식 요청 (Tendermint `GET /status` 등).
+// allowed_paths 대신 호출하는 쪽에서 메서드 정책을 적용
+pub fn build_rpc_url(
+    base_url: &str,
+    credential: Option<&CredentialConfig>,
+    path: &str,
+    query: Option<&str>,
+) -> Result<Url, AppError> {
+    join_url(base_url, credential, &normalize_path(path)?, query)
+}
+
+fn join_url(
+    base_url: &str,
+    credential: Option<&CredentialConfig>,
+    segments: &[String],
+    query: Option<&str>,
+) -> Result<Url, AppError> {
+    let mut url = Url::parse(&credential::apply_to_url(base_url, credential))
+        .map_err(|e| AppError::ProviderError(format!("Invalid upstream URL: {}", e)))?;
+
+    if !segments.is_empty() {
+        let mut path_segments = url
+            .path_segments_mut()
+            .map_err(|_| AppError::ProviderError("Invalid upstream URL".to_string()))?;
+        path_segments.pop_if_empty().extend(segments);
+    }
+
+    // 서명 등을 위해 원문 쿼리를 그대로 보내고, 키와 같은 이름의 파라미터만 제거
+    if let Some(query) = query.filter(|q| !q.is_empty()) {
+        let query = match credential::query_param_name(credential) {
+            Some(reserved) if raw_pairs(query).any(|(_, name, _)| name == reserved) => {
+                raw_pairs(query)
+                    .filter(|(_, name, _)| name != reserved)
+                    .map(|(pair, _, _)| pair)
+                    .collect::<Vec<_>>()
+                    .join("&")
+            }
+            _ => query.to_string(),
+        };
+        if !query.is_empty() {
+            let merged = match url.query().filter(|q| !q.is_empty()) {
+                Some(base) => format!("{}&{}", base, query),
+                None => query,
+            };
+            url.set_query(Some(&merged));
+        }
+    }
+
+    Ok(url)
+}
+
+// 쿼리의 각 쌍 원문과 디코딩한 이름, 값
+pub fn raw_pairs(query: &str) -> impl Iterator<Item = (&str, String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = url::form_urlencoded::parse(pair.as_bytes())
+                .next()
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .unwrap_or_default();
+            (pair, name, value)
+        })
+}
+
+fn normalize_path(path: &str) -> Result<Vec<String>, AppError> {
+    let mut segments = Vec::new();
+
+    for raw in path.split('/') {
+        let segment = percent_decode_str(raw)
+            .decode_utf8()
+            .map_err(|_| AppError::InvalidPath("path is not valid UTF-8".to_string()))?;
+
+        if segment.contains(['/', '\\']) {
+            return Err(AppError::InvalidPath(
+                "encoded slashes are not allowed".to_string(),
+            ));
+        }
+        if segment.chars().any(|c| c.is_control()) {
+            return Err(AppError::InvalidPath(
+                "control characters are not allowed".to_string(),
+            ));
+        }
+
+        match segment.as_ref() {
+            "" | "." => continue,
+            ".." => {
+                return Err(AppError::InvalidPath(
+                    "parent directory segments are not allowed".to_string(),
+                ))
+            }
+            _ => segments.push(segment.into_owned()),
+        }
+    }
+
+    if segments.first().is_some_and(|s| s.ends_with(':')) {
+        return Err(AppError::InvalidPath(
+            "absolute URLs are not allowed".to_string(),
+        ));
     }
+
+    Ok(segments)
+}
+
+fn has_prefix(segments: &[String], prefix: &str) -> bool {
+    let prefix: Vec<&str> = prefix.split('/').filter(|s| !s.is_empty()).collect();
+    prefix.len() <= segments.len() && prefix.iter().zip(segments).all(|(p, s)| *p == s)
 }
@@ -0,0 +1,153 @@
+use std::{path::PathBuf, time::SystemTime};
+
+use thiserror::Error;
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+
+use crate::{config::Settings, state::AppState};
+
+#[derive(Debug, Error)]
+pub enum ReloadError {
+    #[error("Failed to load configuration: {0}")]
+    Load(#[from] config::ConfigError),
+
+    #[error("Invalid configuration: {}", .0.join("; "))]
+    Invalid(Vec<String>),
+}
+
+pub fn reload(state: &AppState) -> Result<(), ReloadError> {
+    let mut settings = Settings::load()?;
+    settings.validate().map_err(ReloadError::Invalid)?;
+
+    let current = state.settings();
+    for section in keep_restart_required(&current, &mut settings) {
+        tracing::warn!(
+            "Configuration section '{}' changed but requires a restart to take effect",
+            section
+        );
+    }
+
+    state.replace_settings(settings);
+    tracing::info!("Configuration reloaded");
+    Ok(())
+}
+
+// 재시작이 필요한 값이 바뀌었으면 현재 값을 유지하고 해당 섹션 이름을 반환
+fn keep_restart_required(current: &Settings, new: &mut Settings) -> Vec<&'static str> {
+    let mut sections = Vec::new();
+
+    if current.server_addr() != new.server_addr() {
+        new.server.host = current.server.host.clone();
+        new.server.port = current.server.port;
+        sections.push("server");
+    }
+    if current.database.backend != new.database.backend
+        || current.database.url != new.database.url
+        || current.database.max_connections != new.database.max_connections
+    {
+        new.database = current.database.clone();
+        sections.push("database");
+    }
+    if current.auth.enabled != new.auth.enabled {
+        new.auth.enabled = current.auth.enabled;
+        sections.push("auth.enabled");
+    }
+    if current.metrics.enabled != new.metrics.enabled {
+        new.metrics.enabled = current.metrics.enabled;
+        sections.push("metrics");
+    }
+    if current.usage.enabled != new.usage.enabled
+        || current.usage.bucket_secs != new.usage.bucket_secs
+    {
+        new.usage.enabled = current.usage.enabled;
+        new.usage.bucket_secs = current.usage.bucket_secs;
+        sections.push("usage");
+    }
+    if current.transactions.enabled != new.transactions.enabled
+        || current.transactions.poll_interval_secs != new.transactions.poll_interval_secs
+    {
+        new.transactions.enabled = current.transactions.enabled;
+        new.transactions.poll_interval_secs = current.transactions.poll_interval_secs;
+        sections.push("transactions");
+    }
+    // 토큰 값 교체는 바로 적용되지만 /admin/* 라우트 등록 여부는 시작 시 결정됨
+    let bind_changed = current.admin.bind != new.admin.bind;
+    let token_toggled = current.admin.token.is_some() != new.admin.token.is_some();
+    if bind_changed {
+        new.admin.bind = current.admin.bind.clone();
+    }
+    if token_toggled {
+        new.admin.token = current.admin.token.clone();
+    }
+    if bind_changed || token_toggled {
+        sections.push("admin");
+    }
+
+    sections
+}
+
+pub fn spawn_file_watcher(
+    state: AppState,
+    interval_secs: u64,
+    tasks: &TaskTracker,
+    shutdown: CancellationToken,
+) {
+    tasks.spawn(async move {
+        let files = Settings::source_files();
+        let mut last_modified = modified_times(&files);
+        let mut interval =
+            tokio::time::interval(tokio::time::Duration::from_secs(interval_secs.max(1)));
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            let modified = modified_times(&files);
+            if modified == last_modified {
+                continue;
+            }
+            last_modified = modified;
+
+            tracing::info!("Configuration files changed, reloading");
+            if let Err(e) = reload(&state) {
+                tracing::error!("Configuration reload rejected: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(unix)]
+pub fn spawn_signal_handler(state: AppState, tasks: &TaskTracker, shutdown: CancellationToken) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(stream) => stream,
+        Err(e) => {
+            tracing::error!("Failed to install SIGHUP handler: {}", e);
+            return;
+        }
+    };
+
+    tasks.spawn(async move {
+        while let Some(()) = tokio::select! {
+            _ = shutdown.cancelled() => None,
+            received = hangup.recv() => received,
+        } {
+            tracing::info!("Received SIGHUP, reloading configuration");
+            if let Err(e) = reload(&state) {
+                tracing::error!("Configuration reload rejected: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn spawn_signal_handler(_state: AppState, _tasks: &TaskTracker, _shutdown: CancellationToken) {}
+
+fn modified_times(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+        .collect()
+}
@@ -0,0 +1,229 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+use tokio::task::JoinSet;
+
+use super::Upstream;
+use crate::{
+    chains::{
+        evm::{self, BlockRef},
+        solana,
+    },
+    config::{
+        redact_url, BlockPinningConfig, BlockPinningMode, ChainKind, NetworkConfig, SolanaConfig,
+    },
+    models::rpc::RpcRequest,
+    providers::jsonrpc,
+    state::AppState,
+};
+
+const STALE_AFTER_POLLS: u32 = 3;
+
+// 업스트림 URL 별 최신 블록 번호. 키는 크리덴셜 적용 전 URL
+#[derive(Clone, Default)]
+pub struct BlockTracker {
+    heads: Arc<Mutex<HashMap<String, (u64, Instant)>>>,
+}
+
+impl BlockTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, url: &str, number: u64) {
+        if let Ok(mut heads) = self.heads.lock() {
+            heads.insert(url.to_string(), (number, Instant::now()));
+        }
+    }
+
+    fn heads(&self, urls: &[&str], max_age: Duration) -> Vec<u64> {
+        let Ok(heads) = self.heads.lock() else {
+            return Vec::new();
+        };
+
+        urls.iter()
+            .filter_map(|url| heads.get(*url))
+            .filter(|(_, at)| at.elapsed() <= max_age)
+            .map(|(number, _)| *number)
+            .collect()
+    }
+
+    pub fn highest(&self, urls: &[&str], max_age: Duration) -> Option<u64> {
+        self.heads(urls, max_age).into_iter().max()
+    }
+
+    // 모든 업스트림이 가진 블록. max_lag 보다 뒤처진 업스트림은 제외
+    pub fn common(&self, urls: &[&str], max_age: Duration, max_lag: u64) -> Option<u64> {
+        let heads = self.heads(urls, max_age);
+        let highest = heads.iter().copied().max()?;
+        heads
+            .into_iter()
+            .filter(|head| highest - head <= max_lag)
+            .min()
+    }
+
+    // required 이상인 URL 을 앞에 두고 나머지는 원래 순서대로 뒤에
+    pub fn order<'a>(&self, urls: &[&'a str], required: u64) -> Vec<&'a str> {
+        let Ok(heads) = self.heads.lock() else {
+            return urls.to_vec();
+        };
+        let (mut ready, behind): (Vec<_>, Vec<_>) = urls
+            .iter()
+            .copied()
+            .partition(|url| heads.get(*url).is_some_and(|(head, _)| *head >= required));
+        ready.extend(behind);
+        ready
+    }
+}
+
+// 이 시간 동안 갱신되지 않은 head 는 무시
+pub fn max_age(poll_interval_secs: u64) -> Duration {
+    Duration::from_secs(poll_interval_secs) * STALE_AFTER_POLLS
+}
+
+// eth_blockNumber 를 로컬에서 응답해야 하면 블록 번호를 반환.
+// rewrite: `latest` 를 모든 업스트림이 가진 블록 번호로 치환
+// route: 요청한 블록 (latest 는 지금까지 본 가장 높은 블록) 이상을 가진 업스트림을 우선
+pub fn pin(
+    tracker: &BlockTracker,
+    network: &NetworkConfig,
+    pinning: &BlockPinningConfig,
+    request: &mut RpcRequest,
+    upstream: &mut Upstream<'_>,
+) -> Option<u64> {
+    let urls = super::jsonrpc_urls(network);
+    let max_age = max_age(pinning.poll_interval_secs);
+
+    let required = match pinning.mode {
+        BlockPinningMode::Rewrite => {
+            let common = tracker.common(&urls, max_age, pinning.max_lag)?;
+            if request.method == evm::BLOCK_NUMBER {
+                return Some(common);
+            }
+            if rewrite_latest(request, common) {
+                tracing::debug!(method = %request.method, block = common, "Pinned latest block");
+            }
+            match evm::block_ref(&request.method, &request.params) {
+                Some(BlockRef::Number(number)) => number,
+                _ => return None,
+            }
+        }
+        BlockPinningMode::Route => match evm::block_ref(&request.method, &request.params) {
+            Some(BlockRef::Latest) => tracker.highest(&urls, max_age)?,
+            Some(BlockRef::Number(number)) => number,
+            _ => return None,
+        },
+    };
+
+    upstream.urls = tracker.order(&upstream.urls, required);
+    None
+}
+
+// 가장 높은 slot 보다 max_slot_lag 이상 뒤처진 Solana 업스트림은 뒤로 보냄
+pub fn prefer_synced(
+    tracker: &BlockTracker,
+    network: &NetworkConfig,
+    config: &SolanaConfig,
+    upstream: &mut Upstream<'_>,
+) {
+    let urls = super::jsonrpc_urls(network);
+    let Some(highest) = tracker.highest(&urls, max_age(config.slot_poll_interval_secs)) else {
+        return;
+    };
+    upstream.urls = tracker.order(&upstream.urls, highest.saturating_sub(config.max_slot_lag));
+}
+
+// pending/safe/finalized 는 그대로 둠
+fn rewrite_latest(request: &mut RpcRequest, number: u64) -> bool {
+    let Some(index) = evm::block_param_index(&request.method) else {
+        return false;
+    };
+    let pinned = json!(format!("0x{:x}", number));
+
+    if request.params.len() == index {
+        request.params.push(pinned);
+        return true;
+    }
+    match request.params.get_mut(index) {
+        Some(param) if param.is_null() || param.as_str() == Some("latest") => {
+            *param = pinned;
+            true
+        }
+        _ => false,
+    }
+}
+
+// block_pinning 이 설정된 EVM 체인은 eth_blockNumber, solana 설정이 있는 Solana 체인은 getSlot 을
+// 모든 JSON-RPC URL 에 보내 head 갱신. last_polled 로 체인별 폴링 주기를 지킴
+pub async fn poll(state: &AppState, last_polled: &mut HashMap<String, Instant>) {
+    let settings = state.settings();
+    let mut polls = JoinSet::new();
+
+    for (chain_id, chain) in &settings.chains {
+        let (method, interval_secs) = match (chain.kind, &chain.block_pinning, &chain.solana) {
+            (Some(ChainKind::Evm), Some(pinning), _) => {
+                (evm::BLOCK_NUMBER, pinning.poll_interval_secs)
+            }
+            (Some(ChainKind::Solana), _, Some(config)) => {
+                (solana::GET_SLOT, config.slot_poll_interval_secs)
+            }
+            _ => continue,
+        };
+
+        let interval = Duration::from_secs(interval_secs);
+        if last_polled
+            .get(chain_id)
+            .is_some_and(|at| at.elapsed() < interval)
+        {
+            continue;
+        }
+        last_polled.insert(chain_id.clone(), Instant::now());
+
+        let networks = std::iter::once(&chain.mainnet).chain(chain.testnets.values());
+        for network in networks {
+            // Solana 는 비교할 업스트림이 하나뿐이면 slot 을 추적할 필요 없음
+            if method == solana::GET_SLOT && super::jsonrpc_urls(network).len() < 2 {
+                continue;
+            }
+            for upstream in super::jsonrpc_upstreams(network) {
+                for url in upstream.urls {
+                    let client = state.http_client.clone();
+                    let tracker = state.blocks.clone();
+                    let url = url.to_string();
+                    let credential = upstream.credential.cloned();
+                    polls.spawn(async move {
+                        let request = RpcRequest {
+                            jsonrpc: "2.0".to_string(),
+                            method: method.to_string(),
+                            params: Vec::new(),
+                            named_params: None,
+                            id: json!(1),
+                        };
+                        let call = jsonrpc::call(&client, &url, credential.as_ref(), &request);
+                        match tokio::time::timeout(interval, call).await {
+                            Ok(Ok(response)) => match response.get("result").and_then(head) {
+                                Some(number) => tracker.record(&url, number),
+                                None => tracing::debug!(url = %redact_url(&url), method, "Invalid block head response"),
+                            },
+                            Ok(Err(e)) => tracing::debug!(url = %redact_url(&url), error = %e, "Block head poll failed"),
+                            Err(_) => tracing::debug!(url = %redact_url(&url), "Block head poll timed out"),
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    while polls.join_next().await.is_some() {}
+}
+
+// eth_blockNumber 는 hex 문자열, getSlot 은 숫자
+fn head(result: &Value) -> Option<u64> {
+    result
+        .as_u64()
+        .or_else(|| result.as_str().and_then(evm::parse_quantity))
+}
@@ -0,0 +1,62 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+// 연속 실패가 이 횟수 이상이면 COOLDOWN 동안 비정상으로 간주
+const FAILURE_THRESHOLD: u32 = 3;
+const COOLDOWN: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default)]
+struct EndpointHealth {
+    consecutive_failures: u32,
+    last_failure: Option<Instant>,
+}
+
+// 실제 요청 결과로만 상태를 갱신하는 수동(passive) 헬스 체크. 키는 크리덴셜 적용 전 URL
+#[derive(Clone, Default)]
+pub struct HealthTracker {
+    endpoints: Arc<Mutex<HashMap<String, EndpointHealth>>>,
+}
+
+impl HealthTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_success(&self, url: &str) {
+        if let Ok(mut endpoints) = self.endpoints.lock() {
+            endpoints.remove(url);
+        }
+    }
+
+    pub fn record_failure(&self, url: &str) {
+        if let Ok(mut endpoints) = self.endpoints.lock() {
+            let health = endpoints.entry(url.to_string()).or_default();
+            health.consecutive_failures += 1;
+            health.last_failure = Some(Instant::now());
+        }
+    }
+
+    pub fn is_healthy(&self, url: &str) -> bool {
+        let Ok(endpoints) = self.endpoints.lock() else {
+            return true;
+        };
+
+        match endpoints.get(url) {
+            Some(health) if health.consecutive_failures >= FAILURE_THRESHOLD => health
+                .last_failure
+                .is_none_or(|at| at.elapsed() >= COOLDOWN),
+            _ => true,
+        }
+    }
+
+    // 정상 엔드포인트를 앞에 두고, 모두 비정상이면 원래 순서대로 전부 시도
+    pub fn order<'a>(&self, urls: &[&'a str]) -> Vec<&'a str> {
+        let (mut healthy, unhealthy): (Vec<_>, Vec<_>) =
+            urls.iter().copied().partition(|url| self.is_healthy(url));
+        healthy.extend(unhealthy);
+        healthy
+    }
+}
@@ -0,0 +1,146 @@
+mod blocks;
+mod health;
+
+pub use blocks::{max_age, pin, poll as poll_block_heads, prefer_synced, BlockTracker};
+pub use health::HealthTracker;
+
+use std::collections::HashSet;
+
+use crate::{
+    chains::evm::{self, BlockRef},
+    config::{glob_match, BlockSelector, ChainKind, CredentialConfig, NetworkConfig, RouteRule},
+    models::rpc::RpcRequest,
+};
+
+pub const DEFAULT_UPSTREAM: &str = "default";
+pub const BROADCAST_UPSTREAM: &str = "broadcast";
+// block_pinning 으로 프록시가 직접 응답한 eth_blockNumber
+pub const PINNED_UPSTREAM: &str = "pinned";
+// 응답 캐시에서 바로 응답
+pub const CACHE_UPSTREAM: &str = "cache";
+// bitcoin JSON-RPC 를 Esplora REST 로 변환해 처리
+pub const ESPLORA_UPSTREAM: &str = "esplora";
+
+#[derive(Debug, Clone)]
+pub struct Upstream<'a> {
+    pub name: &'a str,
+    pub urls: Vec<&'a str>,
+    pub credential: Option<&'a CredentialConfig>,
+}
+
+pub fn upstream<'a>(network: &'a NetworkConfig, name: &str) -> Option<Upstream<'a>> {
+    if name == DEFAULT_UPSTREAM {
+        return network.jsonrpc_url.as_deref().map(|url| Upstream {
+            name: DEFAULT_UPSTREAM,
+            urls: vec![url],
+            credential: network.credential.as_ref(),
+        });
+    }
+
+    network
+        .upstreams
+        .get_key_value(name)
+        .map(|(name, group)| Upstream {
+            name,
+            urls: group.urls.iter().map(String::as_str).collect(),
+            credential: group.credential.as_ref(),
+        })
+}
+
+pub fn select_jsonrpc<'a>(
+    kind: Option<ChainKind>,
+    network: &'a NetworkConfig,
+    request: &RpcRequest,
+) -> Option<Upstream<'a>> {
+    let rule = network
+        .routes
+        .iter()
+        .find(|rule| rule_matches(rule, kind, request));
+
+    if let Some(rule) = rule {
+        match upstream(network, &rule.upstream) {
+            Some(target) => return Some(target),
+            None => tracing::warn!(
+                upstream = %rule.upstream,
+                "Route refers to unknown upstream group, using default"
+            ),
+        }
+    }
+
+    upstream(network, DEFAULT_UPSTREAM)
+}
+
+// 기본 업스트림과 모든 업스트림 그룹
+pub fn jsonrpc_upstreams(network: &NetworkConfig) -> Vec<Upstream<'_>> {
+    upstream(network, DEFAULT_UPSTREAM)
+        .into_iter()
+        .chain(
+            network
+                .upstreams
+                .keys()
+                .filter_map(|name| upstream(network, name)),
+        )
+        .collect()
+}
+
+pub fn jsonrpc_urls(network: &NetworkConfig) -> Vec<&str> {
+    let mut seen = HashSet::new();
+    jsonrpc_upstreams(network)
+        .into_iter()
+        .flat_map(|upstream| upstream.urls)
+        .filter(|url| seen.insert(*url))
+        .collect()
+}
+
+// 트랜잭션 브로드캐스트 대상: 기본 업스트림, 업스트림 그룹의 모든 URL, broadcast_urls 순서.
+// URL 하나당 하나의 Upstream 이며 첫 번째가 기본(primary) 엔드포인트
+pub fn broadcast_targets(network: &NetworkConfig) -> Vec<Upstream<'_>> {
+    let mut targets: Vec<Upstream> = Vec::new();
+
+    for group in jsonrpc_upstreams(network) {
+        for url in &group.urls {
+            targets.push(Upstream {
+                urls: vec![url],
+                ..group.clone()
+            });
+        }
+    }
+
+    for url in &network.broadcast_urls {
+        targets.push(Upstream {
+            name: BROADCAST_UPSTREAM,
+            urls: vec![url],
+            credential: None,
+        });
+    }
+
+    let mut seen = HashSet::new();
+    targets.retain(|target| seen.insert(target.urls[0]));
+    targets
+}
+
+fn rule_matches(rule: &RouteRule, kind: Option<ChainKind>, request: &RpcRequest) -> bool {
+    if !rule
+        .methods
+        .iter()
+        .any(|pattern| glob_match(pattern, &request.method))
+    {
+        return false;
+    }
+
+    let Some(selector) = rule.block else {
+        return true;
+    };
+
+    if kind != Some(ChainKind::Evm) {
+        return false;
+    }
+
+    match evm::block_ref(&request.method, &request.params) {
+        Some(BlockRef::Latest) => selector == BlockSelector::Latest,
+        Some(BlockRef::Earliest | BlockRef::Number(_) | BlockRef::Hash) => {
+            selector == BlockSelector::Historical
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,35 @@
+use tokio_util::sync::CancellationToken;
+
+// SIGTERM 또는 SIGINT 를 받으면 토큰을 취소해 서버와 백그라운드 작업에 종료를 알림
+pub fn spawn_signal_listener(token: CancellationToken) {
+    tokio::spawn(async move {
+        let signal = wait_for_signal().await;
+        tracing::info!("Received {}, shutting down", signal);
+        token.cancel();
+    });
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() -> &'static str {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = match signal(SignalKind::terminate()) {
+        Ok(stream) => stream,
+        Err(e) => {
+            tracing::error!("Failed to install SIGTERM handler: {}", e);
+            let _ = tokio::signal::ctrl_c().await;
+            return "SIGINT";
+        }
+    };
+
+    tokio::select! {
+        _ = terminate.recv() => "SIGTERM",
+        _ = tokio::signal::ctrl_c() => "SIGINT",
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() -> &'static str {
+    let _ = tokio::signal::ctrl_c().await;
+    "Ctrl-C"
+}
@@ -1,22 +1,73 @@
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use metrics_exporter_prometheus::PrometheusHandle;
 use reqwest::Client;
 use sqlx::PgPool;
+use tokio_util::task::TaskTracker;
 
 use crate::auth::ApiKeyRepository;
+use crate::cache::ResponseCache;
 use crate::config::Settings;
+use crate::routing::{BlockTracker, HealthTracker};
+use crate::transactions::TransactionRepository;
+use crate::usage::{UsageRecorder, UsageRepository};
 
 #[derive(Clone)]
 pub struct AppState {
-    pub settings: Settings,
+    settings: Arc<ArcSwap<Settings>>,
     pub http_client: Client,
-    pub api_key_repo: ApiKeyRepository,
+    pub health: HealthTracker,
+    pub blocks: BlockTracker,
+    pub cache: ResponseCache,
+    pub db: Option<PgPool>,
+    pub api_key_repo: Arc<dyn ApiKeyRepository>,
+    pub usage_repo: Option<UsageRepository>,
+    pub usage: Option<UsageRecorder>,
+    pub transactions: Option<TransactionRepository>,
+    pub metrics: Option<PrometheusHandle>,
+    // 응답 후에도 계속되는 작업. 종료 시 이 작업들이 끝나기를 기다림
+    pub tasks: TaskTracker,
 }
 
 impl AppState {
-    pub fn new(settings: Settings, pool: PgPool) -> Self {
+    pub fn new(
+        settings: Settings,
+        db: Option<PgPool>,
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        metrics: Option<PrometheusHandle>,
+    ) -> Self {
+        // 사용량 집계는 PostgreSQL 백엔드에서만 지원
+        let usage_repo = db.clone().map(UsageRepository::new);
+        let usage = (settings.auth.enabled && settings.usage.enabled && usage_repo.is_some())
+            .then(|| UsageRecorder::new(settings.usage.bucket_secs));
+        let transactions = db
+            .clone()
+            .filter(|_| settings.transactions.enabled)
+            .map(TransactionRepository::new);
+
         Self {
-            settings,
+            settings: Arc::new(ArcSwap::from_pointee(settings)),
             http_client: Client::new(),
-            api_key_repo: ApiKeyRepository::new(pool),
+            health: HealthTracker::new(),
+            blocks: BlockTracker::new(),
+            cache: ResponseCache::new(),
+            db,
+            api_key_repo,
+            usage_repo,
+            usage,
+            transactions,
+            metrics,
+            tasks: TaskTracker::new(),
         }
     }
+
+    // 진행 중인 요청은 자신이 가져간 스냅샷으로 끝까지 처리됨
+    pub fn settings(&self) -> Arc<Settings> {
+        self.settings.load_full()
+    }
+
+    pub fn replace_settings(&self, settings: Settings) {
+        self.settings.store(Arc::new(settings));
+    }
 }
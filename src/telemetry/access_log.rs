@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use super::metrics::RequestLabels;
+
+pub fn record(
+    labels: &RequestLabels,
+    api_key_id: Option<Uuid>,
+    status: u16,
+    elapsed: Duration,
+    bytes: Option<u64>,
+) {
+    tracing::info!(
+        target: "access_log",
+        chain = %labels.chain,
+        network = %labels.network,
+        protocol = labels.protocol.as_str(),
+        method = %labels.method,
+        api_key_id = api_key_id.map(|id| id.to_string()),
+        status,
+        upstream = %labels.upstream,
+        latency_ms = elapsed.as_millis() as u64,
+        bytes,
+        "access"
+    );
+}
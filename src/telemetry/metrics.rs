@@ -0,0 +1,114 @@
+use std::{
+    collections::HashSet,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+use ::metrics::{counter, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use sqlx::PgPool;
+
+const REQUESTS_TOTAL: &str = "arpc_requests_total";
+const REQUEST_DURATION: &str = "arpc_request_duration_seconds";
+const REQUEST_ERRORS_TOTAL: &str = "arpc_request_errors_total";
+const AUTH_TOTAL: &str = "arpc_auth_total";
+const DB_POOL_CONNECTIONS: &str = "arpc_db_pool_connections";
+const DB_POOL_IDLE: &str = "arpc_db_pool_idle_connections";
+
+const DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+// 클라이언트가 임의의 메서드 이름을 보내도 레이블 카디널리티가 무한히 늘지 않도록 제한
+const MAX_METHOD_LABELS: usize = 512;
+// 허용되지 않았거나 업스트림이 모르는 메서드는 레이블/사용량 집계에서 이 이름으로 묶음
+pub const OTHER_METHOD: &str = "other";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    JsonRpc,
+    Rest,
+}
+
+impl Protocol {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Protocol::JsonRpc => "jsonrpc",
+            Protocol::Rest => "rest",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestLabels {
+    pub chain: String,
+    pub network: String,
+    pub protocol: Protocol,
+    pub method: String,
+    pub upstream: String,
+}
+
+pub fn install() -> PrometheusHandle {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full(REQUEST_DURATION.to_string()),
+            DURATION_BUCKETS,
+        )
+        .expect("Invalid histogram buckets")
+        .install_recorder()
+        .expect("Failed to install metrics recorder")
+}
+
+pub fn record_request(labels: &RequestLabels, status: u16, elapsed: Duration) {
+    let method = method_label(&labels.method);
+    let common = [
+        ("chain", labels.chain.clone()),
+        ("network", labels.network.clone()),
+        ("protocol", labels.protocol.as_str().to_string()),
+        ("method", method),
+        ("upstream", labels.upstream.clone()),
+    ];
+
+    counter!(
+        REQUESTS_TOTAL,
+        &[&common[..], &[("status", status.to_string())]].concat()
+    )
+    .increment(1);
+    histogram!(REQUEST_DURATION, &common).record(elapsed.as_secs_f64());
+
+    if status >= 400 {
+        counter!(
+            REQUEST_ERRORS_TOTAL,
+            &[&common[..], &[("status", status.to_string())]].concat()
+        )
+        .increment(1);
+    }
+}
+
+pub fn record_auth(outcome: &'static str) {
+    counter!(AUTH_TOTAL, "outcome" => outcome).increment(1);
+}
+
+pub fn record_pool(pool: &PgPool) {
+    gauge!(DB_POOL_CONNECTIONS).set(pool.size() as f64);
+    gauge!(DB_POOL_IDLE).set(pool.num_idle() as f64);
+}
+
+fn method_label(method: &str) -> String {
+    static SEEN: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+    let Ok(mut seen) = SEEN.get_or_init(Default::default).lock() else {
+        return OTHER_METHOD.to_string();
+    };
+
+    if seen.contains(method) {
+        return method.to_string();
+    }
+
+    if seen.len() >= MAX_METHOD_LABELS {
+        return OTHER_METHOD.to_string();
+    }
+
+    seen.insert(method.to_string());
+    method.to_string()
+}
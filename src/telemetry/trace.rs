@@ -0,0 +1,121 @@
+use axum::{body::Body, http::Request};
+use opentelemetry::{global, trace::TracerProvider as _};
+use opentelemetry_http::{HeaderExtractor, HeaderInjector};
+use opentelemetry_otlp::SpanExporter;
+use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider, Resource};
+use reqwest::{header::HeaderMap, RequestBuilder};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+const SERVICE_NAME: &str = "arpc-proxy";
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+pub struct TracingGuard {
+    provider: SdkTracerProvider,
+}
+
+impl TracingGuard {
+    pub fn shutdown(self) {
+        if let Err(e) = self.provider.shutdown() {
+            eprintln!("Failed to shut down tracer provider: {}", e);
+        }
+    }
+}
+
+// OTLP 내보내기는 표준 환경 변수(OTEL_EXPORTER_OTLP_ENDPOINT 등)가 설정된 경우에만 활성화.
+// 내보내지 않더라도 trace context 는 항상 생성해서 업스트림으로 전파
+pub fn init() -> TracingGuard {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let mut builder = SdkTracerProvider::builder().with_resource(resource());
+
+    let otlp_exporter = if otlp_enabled() {
+        match SpanExporter::builder().with_http().build() {
+            Ok(exporter) => Some(exporter),
+            Err(e) => {
+                eprintln!("Failed to initialize OTLP exporter: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let otlp_active = otlp_exporter.is_some();
+    if let Some(exporter) = otlp_exporter {
+        builder = builder.with_batch_exporter(exporter);
+    }
+
+    let provider = builder.build();
+    let tracer = provider.tracer(SERVICE_NAME);
+
+    // LOG_FORMAT=json 이면 모든 로그(접근 로그 포함)를 JSON 한 줄씩 출력
+    let json = std::env::var("LOG_FORMAT").is_ok_and(|v| v.eq_ignore_ascii_case("json"));
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        ))
+        .with(json.then(|| tracing_subscriber::fmt::layer().json()))
+        .with((!json).then(tracing_subscriber::fmt::layer))
+        .with(tracing_opentelemetry::layer().with_tracer(tracer))
+        .init();
+
+    if otlp_active {
+        tracing::info!("OTLP trace export enabled");
+    }
+
+    TracingGuard { provider }
+}
+
+pub fn make_span(request: &Request<Body>) -> Span {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        uri = %request.uri().path(),
+        otel.kind = "server",
+    );
+
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    let _ = span.set_parent(parent);
+
+    span
+}
+
+pub fn inject_context(request: RequestBuilder) -> RequestBuilder {
+    let context = Span::current().context();
+    let mut headers = HeaderMap::new();
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut HeaderInjector(&mut headers))
+    });
+
+    request.headers(headers)
+}
+
+fn otlp_enabled() -> bool {
+    [
+        "OTEL_EXPORTER_OTLP_ENDPOINT",
+        "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT",
+    ]
+    .iter()
+    .any(|var| std::env::var(var).is_ok_and(|v| !v.is_empty()))
+}
+
+fn resource() -> Resource {
+    let builder = Resource::builder();
+    if std::env::var("OTEL_SERVICE_NAME").is_ok() {
+        builder.build()
+    } else {
+        builder.with_service_name(SERVICE_NAME).build()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod model;
+pub mod poller;
+pub mod repository;
+
+pub use repository::TransactionRepository;
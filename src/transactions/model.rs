@@ -0,0 +1,190 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionStatus {
+    Pending,
+    Confirmed,
+    Dropped,
+}
+
+impl TransactionStatus {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(TransactionStatus::Pending),
+            "confirmed" => Some(TransactionStatus::Confirmed),
+            "dropped" => Some(TransactionStatus::Dropped),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionStatus::Pending => "pending",
+            TransactionStatus::Confirmed => "confirmed",
+            TransactionStatus::Dropped => "dropped",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTransaction {
+    pub api_key_id: Option<Uuid>,
+    pub chain: String,
+    pub network: String,
+    pub hash: Option<String>,
+    pub sender: Option<String>,
+    pub upstream: String,
+    pub accepted: bool,
+    pub error: Option<String>,
+}
+
+impl NewTransaction {
+    // 업스트림이 거부한 트랜잭션은 추적할 필요가 없으므로 바로 dropped
+    pub fn status(&self) -> TransactionStatus {
+        if self.accepted && self.hash.is_some() {
+            TransactionStatus::Pending
+        } else {
+            TransactionStatus::Dropped
+        }
+    }
+}
+
+// 업스트림 응답에서 해석한 전송 결과
+#[derive(Debug, Clone, Default)]
+pub struct Submission {
+    pub accepted: bool,
+    pub hash: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Submission {
+    pub fn from_jsonrpc(body: &[u8]) -> Self {
+        let Ok(value) = serde_json::from_slice::<Value>(body) else {
+            return Self::rejected("invalid upstream response".to_string());
+        };
+
+        if let Some(error) = value.get("error").filter(|error| !error.is_null()) {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| error.to_string());
+            return Self::rejected(message);
+        }
+
+        match value.get("result") {
+            Some(Value::String(hash)) => Self {
+                accepted: true,
+                hash: Some(hash.clone()),
+                error: None,
+            },
+            Some(result) if !result.is_null() => Self {
+                accepted: true,
+                ..Default::default()
+            },
+            _ => Self::rejected("missing result".to_string()),
+        }
+    }
+
+    // Esplora 는 txid 를 텍스트로, Horizon 은 JSON ({ "hash": .. }) 으로 응답
+    pub fn from_rest(status: StatusCode, body: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(body).trim().to_string();
+        let json = serde_json::from_slice::<Value>(body).ok();
+        let hash = json
+            .as_ref()
+            .and_then(|json| {
+                json.get("hash")
+                    .or_else(|| json.pointer("/extras/hash"))
+                    .and_then(Value::as_str)
+            })
+            .map(str::to_string);
+
+        // Horizon /transactions_async 는 거부된 경우에도 tx_status = "ERROR" 를 담아 응답
+        let async_status = json
+            .as_ref()
+            .and_then(|json| json.get("tx_status"))
+            .and_then(Value::as_str);
+
+        if status.is_success() && async_status != Some("ERROR") {
+            return Self {
+                accepted: true,
+                hash: hash.or_else(|| (json.is_none() && !text.is_empty()).then_some(text)),
+                error: None,
+            };
+        }
+
+        let error = json
+            .as_ref()
+            .and_then(|json| {
+                json.pointer("/extras/result_codes")
+                    .map(Value::to_string)
+                    .or_else(|| {
+                        json.get("title")
+                            .and_then(Value::as_str)
+                            .map(str::to_string)
+                    })
+            })
+            .unwrap_or(text);
+        Self {
+            accepted: false,
+            hash,
+            error: Some(error),
+        }
+    }
+
+    fn rejected(error: String) -> Self {
+        Self {
+            accepted: false,
+            hash: None,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionQuery {
+    pub api_key_id: Option<Uuid>,
+    pub device_id: Option<String>,
+    pub chain: Option<String>,
+    pub hash: Option<String>,
+    pub status: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct TransactionRecord {
+    pub id: Uuid,
+    pub api_key_id: Option<Uuid>,
+    pub device_id: Option<String>,
+    pub chain: String,
+    pub network: String,
+    pub hash: Option<String>,
+    pub sender: Option<String>,
+    pub upstream: String,
+    pub accepted: bool,
+    pub error: Option<String>,
+    pub status: String,
+    pub submitted_at: DateTime<Utc>,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub confirmed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct PendingTransaction {
+    pub id: Uuid,
+    pub chain: String,
+    pub network: String,
+    pub hash: String,
+    pub status: String,
+    pub submitted_at: DateTime<Utc>,
+}
+
+impl PendingTransaction {
+    pub fn status(&self) -> TransactionStatus {
+        TransactionStatus::parse(&self.status).unwrap_or(TransactionStatus::Pending)
+    }
+}
@@ -0,0 +1,297 @@
+use chrono::{Duration, Utc};
+use serde_json::{json, Value};
+use tracing::Instrument;
+
+use super::{
+    model::{PendingTransaction, TransactionStatus},
+    TransactionRepository,
+};
+use crate::{
+    config::{ChainConfig, ChainKind, NetworkConfig, Settings},
+    error::AppError,
+    models::rpc::RpcRequest,
+    providers::{credential, jsonrpc, rest},
+    routing,
+    state::AppState,
+    telemetry::trace,
+};
+
+const MAINNET: &str = "mainnet";
+
+// pending 트랜잭션의 상태를 체인에서 조회해 갱신.
+// drop_after_secs 가 지나도록 블록에도 멤풀에도 없으면 dropped 로 처리하고,
+// dropped 도 recheck_dropped_secs 동안은 계속 조회해 뒤늦게 포함되면 confirmed 로 갱신
+pub async fn poll(state: &AppState, repo: &TransactionRepository) {
+    let settings = state.settings();
+    let config = &settings.transactions;
+
+    let pending = match repo
+        .pending(config.batch_size, config.recheck_dropped_secs)
+        .await
+    {
+        Ok(pending) => pending,
+        Err(e) => {
+            tracing::error!("Failed to load pending transactions: {:?}", e);
+            return;
+        }
+    };
+
+    let drop_after = Duration::seconds(config.drop_after_secs);
+    for transaction in pending {
+        let status = match next_status(state, &settings, &transaction, drop_after).await {
+            Ok(status) => status,
+            Err(e) => {
+                tracing::debug!(
+                    chain = %transaction.chain,
+                    hash = %transaction.hash,
+                    error = %e,
+                    "Transaction status lookup failed"
+                );
+                // 조회 실패 시 상태를 바꾸지 않고 확인 시각만 갱신
+                transaction.status()
+            }
+        };
+
+        if status != transaction.status() {
+            tracing::info!(
+                chain = %transaction.chain,
+                network = %transaction.network,
+                hash = %transaction.hash,
+                status = status.as_str(),
+                "Transaction status changed"
+            );
+        }
+
+        if let Err(e) = repo.update_status(transaction.id, status).await {
+            tracing::error!("Failed to update transaction status: {:?}", e);
+        }
+    }
+}
+
+// 블록에 포함됐으면 confirmed. 아직 멤풀에 있으면 drop_after_secs 가 지나도 pending 유지
+async fn next_status(
+    state: &AppState,
+    settings: &Settings,
+    transaction: &PendingTransaction,
+    drop_after: Duration,
+) -> Result<TransactionStatus, AppError> {
+    if is_confirmed(state, settings, transaction).await? {
+        return Ok(TransactionStatus::Confirmed);
+    }
+
+    let current = transaction.status();
+    if current == TransactionStatus::Dropped || Utc::now() - transaction.submitted_at <= drop_after
+    {
+        return Ok(current);
+    }
+    if in_mempool(state, settings, transaction).await? {
+        Ok(TransactionStatus::Pending)
+    } else {
+        Ok(TransactionStatus::Dropped)
+    }
+}
+
+fn resolve<'a>(
+    settings: &'a Settings,
+    transaction: &PendingTransaction,
+) -> Result<(&'a ChainConfig, &'a NetworkConfig), AppError> {
+    let chain = settings
+        .get_chain(&transaction.chain)
+        .ok_or_else(|| AppError::ChainNotFound(transaction.chain.clone()))?;
+    let network = chain
+        .get_network((transaction.network != MAINNET).then_some(transaction.network.as_str()))
+        .ok_or_else(|| AppError::NetworkNotFound(transaction.network.clone()))?;
+    Ok((chain, network))
+}
+
+async fn is_confirmed(
+    state: &AppState,
+    settings: &Settings,
+    transaction: &PendingTransaction,
+) -> Result<bool, AppError> {
+    let (chain, network) = resolve(settings, transaction)?;
+    let hash = transaction.hash.as_str();
+
+    match chain.kind {
+        Some(ChainKind::Evm) => {
+            let receipt = call(
+                state,
+                network,
+                "eth_getTransactionReceipt",
+                vec![json!(hash)],
+            )
+            .await?;
+            Ok(!receipt.is_null())
+        }
+        Some(ChainKind::Solana) => {
+            let statuses = call(
+                state,
+                network,
+                "getSignatureStatuses",
+                vec![json!([hash]), json!({ "searchTransactionHistory": true })],
+            )
+            .await?;
+            let status = statuses
+                .pointer("/value/0/confirmationStatus")
+                .and_then(Value::as_str);
+            Ok(matches!(status, Some("confirmed" | "finalized")))
+        }
+        Some(ChainKind::Sui) => {
+            let block = call(state, network, "sui_getTransactionBlock", vec![json!(hash)]).await;
+            Ok(block.is_ok_and(|block| !block.is_null()))
+        }
+        Some(ChainKind::Bitcoin) if network.has_rest() => {
+            let status = get(state, network, &format!("tx/{}/status", hash)).await?;
+            Ok(status
+                .and_then(|status| status.get("confirmed").and_then(Value::as_bool))
+                .unwrap_or(false))
+        }
+        Some(ChainKind::Bitcoin) => {
+            let raw = bitcoin_transaction(state, network, hash).await?;
+            Ok(raw
+                .get("confirmations")
+                .and_then(Value::as_u64)
+                .is_some_and(|confirmations| confirmations > 0))
+        }
+        // Horizon 은 원장에 포함된 트랜잭션만 조회됨
+        Some(ChainKind::Stellar) => Ok(get(state, network, &format!("transactions/{}", hash))
+            .await?
+            .is_some()),
+        _ => Ok(false),
+    }
+}
+
+// 멤풀 조회를 지원하지 않는 체인은 false (drop_after_secs 가 지나면 dropped)
+async fn in_mempool(
+    state: &AppState,
+    settings: &Settings,
+    transaction: &PendingTransaction,
+) -> Result<bool, AppError> {
+    let (chain, network) = resolve(settings, transaction)?;
+    let hash = transaction.hash.as_str();
+
+    match chain.kind {
+        Some(ChainKind::Evm) => {
+            let transaction = call(
+                state,
+                network,
+                "eth_getTransactionByHash",
+                vec![json!(hash)],
+            )
+            .await?;
+            Ok(!transaction.is_null())
+        }
+        Some(ChainKind::Bitcoin) if network.has_rest() => {
+            Ok(get(state, network, &format!("tx/{}", hash))
+                .await?
+                .is_some())
+        }
+        Some(ChainKind::Bitcoin) => Ok(!bitcoin_transaction(state, network, hash).await?.is_null()),
+        _ => Ok(false),
+    }
+}
+
+// 멤풀에도 블록에도 없으면 (RPC_INVALID_ADDRESS_OR_KEY) null
+async fn bitcoin_transaction(
+    state: &AppState,
+    network: &NetworkConfig,
+    hash: &str,
+) -> Result<Value, AppError> {
+    let response = send(
+        state,
+        network,
+        "getrawtransaction",
+        vec![json!(hash), json!(true)],
+    )
+    .await?;
+    if response.pointer("/error/code").and_then(Value::as_i64) == Some(-5) {
+        return Ok(Value::Null);
+    }
+    result(response)
+}
+
+async fn call(
+    state: &AppState,
+    network: &NetworkConfig,
+    method: &str,
+    params: Vec<Value>,
+) -> Result<Value, AppError> {
+    result(send(state, network, method, params).await?)
+}
+
+// JSON-RPC 에러 응답은 조회 실패로 취급
+fn result(response: Value) -> Result<Value, AppError> {
+    if let Some(error) = response.get("error").filter(|error| !error.is_null()) {
+        return Err(AppError::ProviderError(error.to_string()));
+    }
+    Ok(response.get("result").cloned().unwrap_or(Value::Null))
+}
+
+async fn send(
+    state: &AppState,
+    network: &NetworkConfig,
+    method: &str,
+    params: Vec<Value>,
+) -> Result<Value, AppError> {
+    let upstream = routing::upstream(network, routing::DEFAULT_UPSTREAM).ok_or_else(|| {
+        AppError::ProtocolMismatch(format!(
+            "Network '{}' has no JSON-RPC endpoint",
+            network.name
+        ))
+    })?;
+    let request = RpcRequest {
+        jsonrpc: "2.0".to_string(),
+        method: method.to_string(),
+        params,
+        named_params: None,
+        id: json!(1),
+    };
+
+    let mut last_error = AppError::ProviderError("no endpoints".to_string());
+    for url in state.health.order(&upstream.urls) {
+        match jsonrpc::call(&state.http_client, url, upstream.credential, &request).await {
+            Ok(response) => return Ok(response),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+// 404 이면 None
+async fn get(
+    state: &AppState,
+    network: &NetworkConfig,
+    path: &str,
+) -> Result<Option<Value>, AppError> {
+    let url = rest::build_url(network, path, None)?;
+    let span = tracing::info_span!(
+        "upstream.rest",
+        otel.kind = "client",
+        http.method = "GET",
+        server.address = url.host_str().unwrap_or_default(),
+    );
+    let request =
+        credential::apply_to_request(state.http_client.get(url), network.credential.as_ref());
+    let response = span
+        .in_scope(|| trace::inject_context(request))
+        .send()
+        .instrument(span)
+        .await
+        .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(AppError::ProviderError(format!(
+            "Upstream returned {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json()
+        .await
+        .map(Some)
+        .map_err(|e| AppError::ProviderError(credential::upstream_error(e)))
+}
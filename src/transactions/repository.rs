@@ -0,0 +1,125 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::model::{
+    NewTransaction, PendingTransaction, TransactionQuery, TransactionRecord, TransactionStatus,
+};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
+#[derive(Clone)]
+pub struct TransactionRepository {
+    pool: PgPool,
+}
+
+impl TransactionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, transaction: &NewTransaction) -> Result<Uuid, sqlx::Error> {
+        let id = Uuid::new_v4();
+
+        sqlx::query(
+            r#"
+            INSERT INTO transactions
+                (id, api_key_id, chain, network, hash, sender, upstream, accepted, error, status)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(id)
+        .bind(transaction.api_key_id)
+        .bind(&transaction.chain)
+        .bind(&transaction.network)
+        .bind(transaction.hash.as_deref())
+        .bind(transaction.sender.as_deref())
+        .bind(&transaction.upstream)
+        .bind(transaction.accepted)
+        .bind(transaction.error.as_deref())
+        .bind(transaction.status().as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    pub async fn list(
+        &self,
+        query: &TransactionQuery,
+    ) -> Result<Vec<TransactionRecord>, sqlx::Error> {
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        sqlx::query_as::<_, TransactionRecord>(
+            r#"
+            SELECT t.id, t.api_key_id, k.device_id, t.chain, t.network, t.hash, t.sender,
+                   t.upstream, t.accepted, t.error, t.status, t.submitted_at, t.checked_at,
+                   t.confirmed_at
+            FROM transactions t
+            LEFT JOIN api_keys k ON k.id = t.api_key_id
+            WHERE ($1::UUID IS NULL OR t.api_key_id = $1)
+              AND ($2::VARCHAR IS NULL OR k.device_id = $2)
+              AND ($3::VARCHAR IS NULL OR t.chain = $3)
+              AND ($4::VARCHAR IS NULL OR t.hash = $4)
+              AND ($5::VARCHAR IS NULL OR t.status = $5)
+            ORDER BY t.submitted_at DESC
+            LIMIT $6
+            "#,
+        )
+        .bind(query.api_key_id)
+        .bind(query.device_id.as_deref())
+        .bind(query.chain.as_deref())
+        .bind(query.hash.as_deref())
+        .bind(query.status.as_deref())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    // 가장 오래 확인하지 않은 것부터. 업스트림이 받아들인 뒤 dropped 된 트랜잭션은
+    // 전송 후 recheck_secs 동안 다시 조회
+    pub async fn pending(
+        &self,
+        limit: i64,
+        recheck_secs: i64,
+    ) -> Result<Vec<PendingTransaction>, sqlx::Error> {
+        sqlx::query_as::<_, PendingTransaction>(
+            r#"
+            SELECT id, chain, network, hash, status, submitted_at
+            FROM transactions
+            WHERE hash IS NOT NULL
+              AND (status = 'pending'
+                OR (status = 'dropped' AND accepted
+                    AND submitted_at > NOW() - make_interval(secs => $2)))
+            ORDER BY checked_at ASC NULLS FIRST, submitted_at ASC
+            LIMIT $1
+            "#,
+        )
+        .bind(limit)
+        .bind(recheck_secs as f64)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    pub async fn update_status(
+        &self,
+        id: Uuid,
+        status: TransactionStatus,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE transactions
+            SET status = $2,
+                checked_at = NOW(),
+                confirmed_at = CASE WHEN $2 = 'confirmed' THEN NOW() ELSE confirmed_at END
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(status.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
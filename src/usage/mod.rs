@@ -0,0 +1,6 @@
+pub mod model;
+pub mod recorder;
+pub mod repository;
+
+pub use recorder::UsageRecorder;
+pub use repository::UsageRepository;
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsageKey {
+    pub api_key_id: Uuid,
+    pub chain: String,
+    pub network: String,
+    pub method: String,
+    pub bucket_start: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UsageCounts {
+    pub requests: i64,
+    pub errors: i64,
+    pub response_bytes: i64,
+}
+
+impl UsageCounts {
+    pub fn merge(&mut self, other: UsageCounts) {
+        self.requests += other.requests;
+        self.errors += other.errors;
+        self.response_bytes += other.response_bytes;
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsageQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    pub api_key_id: Option<Uuid>,
+    pub device_id: Option<String>,
+    pub chain: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct UsageSummary {
+    pub api_key_id: Uuid,
+    pub device_id: Option<String>,
+    pub chain: String,
+    pub network: String,
+    pub method: String,
+    pub request_count: i64,
+    pub error_count: i64,
+    pub response_bytes: i64,
+}
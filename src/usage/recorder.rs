@@ -0,0 +1,95 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::{
+    model::{UsageCounts, UsageKey},
+    repository::UsageRepository,
+};
+use crate::telemetry::metrics;
+
+const MAX_METHOD_LEN: usize = 128;
+// flush 사이에 쌓이는 버킷 수 상한. 넘으면 새 메서드는 "other" 로 묶음
+const MAX_BUCKETS: usize = 50_000;
+
+#[derive(Clone)]
+pub struct UsageRecorder {
+    bucket_secs: i64,
+    buckets: Arc<Mutex<HashMap<UsageKey, UsageCounts>>>,
+}
+
+impl UsageRecorder {
+    pub fn new(bucket_secs: i64) -> Self {
+        Self {
+            bucket_secs: bucket_secs.max(1),
+            buckets: Arc::default(),
+        }
+    }
+
+    pub fn record(
+        &self,
+        api_key_id: Uuid,
+        chain: &str,
+        network: &str,
+        method: &str,
+        is_error: bool,
+        response_bytes: u64,
+    ) {
+        let mut key = UsageKey {
+            api_key_id,
+            chain: chain.to_string(),
+            network: network.to_string(),
+            method: method.chars().take(MAX_METHOD_LEN).collect(),
+            bucket_start: self.bucket_start(Utc::now()),
+        };
+        let counts = UsageCounts {
+            requests: 1,
+            errors: is_error as i64,
+            response_bytes: response_bytes as i64,
+        };
+
+        if let Ok(mut buckets) = self.buckets.lock() {
+            if buckets.len() >= MAX_BUCKETS && !buckets.contains_key(&key) {
+                key.method = metrics::OTHER_METHOD.to_string();
+            }
+            buckets.entry(key).or_default().merge(counts);
+        }
+    }
+
+    pub fn drain(&self) -> Vec<(UsageKey, UsageCounts)> {
+        match self.buckets.lock() {
+            Ok(mut buckets) => buckets.drain().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    // DB 기록에 실패한 항목은 다음 flush 때 다시 시도
+    pub fn restore(&self, entries: Vec<(UsageKey, UsageCounts)>) {
+        if let Ok(mut buckets) = self.buckets.lock() {
+            for (key, counts) in entries {
+                buckets.entry(key).or_default().merge(counts);
+            }
+        }
+    }
+
+    pub async fn flush(&self, repo: &UsageRepository) {
+        let entries = self.drain();
+        if entries.is_empty() {
+            return;
+        }
+
+        if let Err(e) = repo.add(&entries).await {
+            tracing::error!("Failed to flush usage records: {:?}", e);
+            self.restore(entries);
+        }
+    }
+
+    fn bucket_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let ts = now.timestamp();
+        DateTime::from_timestamp(ts - ts.rem_euclid(self.bucket_secs), 0).unwrap_or(now)
+    }
+}
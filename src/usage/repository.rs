@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use super::model::{UsageCounts, UsageKey, UsageQuery, UsageSummary};
+
+const DEFAULT_LIMIT: i64 = 100;
+const MAX_LIMIT: i64 = 1000;
+
+#[derive(Clone)]
+pub struct UsageRepository {
+    pool: PgPool,
+}
+
+impl UsageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn add(&self, entries: &[(UsageKey, UsageCounts)]) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        for (key, counts) in entries {
+            sqlx::query(
+                r#"
+                INSERT INTO api_key_usage
+                    (api_key_id, chain, network, method, bucket_start, request_count, error_count, response_bytes)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (api_key_id, chain, network, method, bucket_start) DO UPDATE SET
+                    request_count = api_key_usage.request_count + EXCLUDED.request_count,
+                    error_count = api_key_usage.error_count + EXCLUDED.error_count,
+                    response_bytes = api_key_usage.response_bytes + EXCLUDED.response_bytes
+                "#,
+            )
+            .bind(key.api_key_id)
+            .bind(&key.chain)
+            .bind(&key.network)
+            .bind(&key.method)
+            .bind(key.bucket_start)
+            .bind(counts.requests)
+            .bind(counts.errors)
+            .bind(counts.response_bytes)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    pub async fn summarize(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        query: &UsageQuery,
+    ) -> Result<Vec<UsageSummary>, sqlx::Error> {
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        sqlx::query_as::<_, UsageSummary>(
+            r#"
+            SELECT u.api_key_id, k.device_id, u.chain, u.network, u.method,
+                   SUM(u.request_count)::BIGINT AS request_count,
+                   SUM(u.error_count)::BIGINT AS error_count,
+                   SUM(u.response_bytes)::BIGINT AS response_bytes
+            FROM api_key_usage u
+            LEFT JOIN api_keys k ON k.id = u.api_key_id
+            WHERE u.bucket_start >= $1 AND u.bucket_start < $2
+              AND ($3::UUID IS NULL OR u.api_key_id = $3)
+              AND ($4::VARCHAR IS NULL OR k.device_id = $4)
+              AND ($5::VARCHAR IS NULL OR u.chain = $5)
+            GROUP BY u.api_key_id, k.device_id, u.chain, u.network, u.method
+            ORDER BY request_count DESC
+            LIMIT $6
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(query.api_key_id)
+        .bind(query.device_id.as_deref())
+        .bind(query.chain.as_deref())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+    }
+}